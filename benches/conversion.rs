@@ -0,0 +1,84 @@
+use ieee_754::IEEE754;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u32 = 1_000_000;
+
+/// Decoding as it was done before the integer based core: every bit becomes
+/// a `u8`, gets joined into strings and parsed back with `from_str_radix`.
+fn legacy_to_32bit_float(values: Vec<u32>) -> Option<f32> {
+    let mut binaries: Vec<u8> = Vec::new();
+    for v in values.iter() {
+        for x in format!("{:08b}", v).chars() {
+            binaries.push(u8::from_str_radix(&x.to_string(), 2).ok()?);
+        }
+    }
+    let exponent_str: String = binaries[1..9]
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .concat();
+    let exponent = u32::from_str_radix(&exponent_str, 2).ok()? as i32 - 127;
+    let mantissa = &binaries[9..];
+    let mut msb_values: Vec<u8> = mantissa.get(0..exponent as usize)?.to_vec();
+    msb_values.insert(0, 1);
+    let msb_str: String = msb_values
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .concat();
+    let msb = i32::from_str_radix(&msb_str, 2).ok()? as f32;
+    let mut lsb: f32 = 0.0;
+    for (index, value) in mantissa[exponent as usize..].iter().enumerate() {
+        lsb += *value as f32 * 2f32.powi(-(index as i32 + 1));
+    }
+    let value = msb + lsb;
+    Some(if binaries[0] == 1 { -value } else { value })
+}
+
+fn bench<F: FnMut() -> R, R>(name: &str, mut f: F) -> f64 {
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f());
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let nanos = start.elapsed().as_nanos() as f64 / ITERATIONS as f64;
+    println!("{:<40} {:>10.1} ns/op", name, nanos);
+    nanos
+}
+
+fn main() {
+    let bytes = [0xc0, 0x2d, 0xf8, 0x54];
+    let values: Vec<u32> = bytes.iter().map(|b| *b as u32).collect();
+    assert_eq!(legacy_to_32bit_float(values.clone()), Some(-2.7182817));
+
+    let legacy = bench("string based decoding used before (f32)", || {
+        legacy_to_32bit_float(black_box(values.clone()))
+    });
+    let vec_api = bench("IEEE754::to_32bit_float", || {
+        IEEE754::to_32bit_float(black_box(values.clone()))
+    });
+    let decode = bench("IEEE754::decode_32bit", || {
+        IEEE754::decode_32bit(black_box(bytes))
+    });
+    bench("IEEE754::decode_64bit", || {
+        IEEE754::decode_64bit(black_box([0x40, 0x09, 0x21, 0xfb, 0x54, 0x44, 0x2d, 0x18]))
+    });
+    bench("IEEE754::encode_32bit", || {
+        IEEE754::encode_32bit(black_box(-2.7182817))
+    });
+    bench("IEEE754::to_32bit_hex", || {
+        IEEE754::to_32bit_hex(black_box(-33.333332))
+    });
+    println!();
+    println!(
+        "to_32bit_float speedup over legacy: {:.0}x",
+        legacy / vec_api
+    );
+    println!(
+        "decode_32bit speedup over legacy:   {:.0}x",
+        legacy / decode
+    );
+}
@@ -0,0 +1,203 @@
+use ieee_754::ieee754::{
+    BinaryParser, DecimalFormatter, DecimalParser, Diagram, Explainer, FormatOptions, Grouping,
+    HexFloat, HexParser, PrecisionReport,
+};
+use ieee_754::Error;
+
+use crate::cli::options::{InputKind, Options, OutputKind};
+
+/// Reads `input` as a bit pattern of `options.format`.
+pub fn parse_value(input: &str, options: &Options) -> Result<u64, Error> {
+    let format = options.format;
+    match input_kind(input, options) {
+        InputKind::Hex => HexParser::parse(input, format, options.endianness),
+        InputKind::Binary => BinaryParser::parse(input, format, options.endianness),
+        _ => DecimalParser::parse(input, format, options.rounding),
+    }
+}
+
+/// Traces the encoding of a decimal literal, or the decoding of any other
+/// input.
+pub fn explain(input: &str, options: &Options) -> Result<String, Error> {
+    let is_decimal = input_kind(input, options) == InputKind::Decimal
+        && !input.to_ascii_lowercase().contains("0x");
+    let trace = if is_decimal {
+        Explainer::encode(input, options.format, options.rounding)?
+    } else {
+        Explainer::decode(options.format, parse_value(input, options)?)
+    };
+    Ok(if options.markdown {
+        trace.to_markdown()
+    } else {
+        trace.to_text()
+    })
+}
+
+/// Reports how much precision a decimal literal loses when stored.
+pub fn precision(input: &str, options: &Options) -> Result<String, Error> {
+    Ok(PrecisionReport::analyze(input, options.format, options.rounding)?.to_text())
+}
+
+fn input_kind(input: &str, options: &Options) -> InputKind {
+    let lowercase = input.trim().to_ascii_lowercase();
+    match options.input {
+        InputKind::Auto if lowercase.starts_with("0x") => {
+            if lowercase.contains(['.', 'p']) {
+                InputKind::Decimal
+            } else {
+                InputKind::Hex
+            }
+        }
+        InputKind::Auto if lowercase.starts_with("0b") => InputKind::Binary,
+        InputKind::Auto => InputKind::Decimal,
+        kind => kind,
+    }
+}
+
+pub fn render(input: &str, bits: u64, options: &Options) -> String {
+    let format = options.format;
+    let hex = FormatOptions::new()
+        .prefix(true)
+        .endianness(options.endianness)
+        .format_hex(format, bits);
+    let binary = FormatOptions::new()
+        .endianness(options.endianness)
+        .format_binary(format, bits);
+    match options.output {
+        OutputKind::Decimal => return DecimalFormatter::shortest(format, bits),
+        OutputKind::Hex => return hex,
+        OutputKind::Binary => return binary,
+        OutputKind::Svg => return Diagram::svg(format, bits).trim_end().to_string(),
+        OutputKind::Html => return Diagram::html(format, bits).trim_end().to_string(),
+        // Precision reports are built from the input text by `precision`.
+        OutputKind::All | OutputKind::Precision => {}
+    }
+
+    let fields = FormatOptions::new()
+        .grouping(Grouping::Fields)
+        .format_binary(format, bits);
+    let biased_exponent = format.biased_exponent(bits);
+    let exponent = if !format.is_finite(bits) {
+        format!("biased {}, reserved", biased_exponent)
+    } else if biased_exponent == 0 {
+        format!("biased 0, unbiased {}", format.min_exponent())
+    } else {
+        format!(
+            "biased {}, unbiased {}",
+            biased_exponent,
+            biased_exponent as i32 - format.bias()
+        )
+    };
+    let rows = [
+        ("input", input.trim().to_string()),
+        (
+            "format",
+            format!(
+                "{} (1 sign, {} exponent, {} mantissa bits)",
+                format,
+                format.exponent_bits(),
+                format.mantissa_bits()
+            ),
+        ),
+        ("decimal", DecimalFormatter::shortest(format, bits)),
+        ("exact", DecimalFormatter::exact(format, bits)),
+        ("hex", hex),
+        ("binary", fields),
+        ("hex float", HexFloat::format(format, bits)),
+        (
+            "sign",
+            format!(
+                "{} ({})",
+                u8::from(format.sign(bits)),
+                if format.sign(bits) { "-" } else { "+" }
+            ),
+        ),
+        (
+            "exponent",
+            format!(
+                "{:0width$b} ({})",
+                biased_exponent,
+                exponent,
+                width = format.exponent_bits() as usize
+            ),
+        ),
+        (
+            "mantissa",
+            format!(
+                "{:0width$b} (0x{:X})",
+                format.mantissa(bits),
+                format.mantissa(bits),
+                width = format.mantissa_bits() as usize
+            ),
+        ),
+        ("class", format.classify(bits).to_string()),
+    ];
+    rows.iter()
+        .map(|(label, value)| format!("{:<10} {}", format!("{}:", label), value))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ieee_754::ieee754::{Endianness, Format, RoundingMode};
+
+    #[test]
+    fn test_parse_value() {
+        let options = Options::default();
+        assert_eq!(parse_value("0.1", &options), Ok(0x3DCCCCCD));
+        assert_eq!(parse_value("0x3DCCCCCD", &options), Ok(0x3DCCCCCD));
+        assert_eq!(parse_value("0x1.99999ap-4", &options), Ok(0x3DCCCCCD));
+        assert_eq!(
+            parse_value("0b0_01111011_10011001100110011001101", &options),
+            Ok(0x3DCCCCCD)
+        );
+        let options = Options {
+            input: InputKind::Hex,
+            endianness: Endianness::Little,
+            ..Options::default()
+        };
+        assert_eq!(parse_value("CD CC CC 3D", &options), Ok(0x3DCCCCCD));
+        let options = Options {
+            rounding: RoundingMode::TowardZero,
+            ..Options::default()
+        };
+        assert_eq!(parse_value("0.1", &options), Ok(0x3DCCCCCC));
+    }
+
+    #[test]
+    fn test_render() {
+        let options = Options {
+            format: Format::BINARY16,
+            ..Options::default()
+        };
+        assert_eq!(
+            render(" 0.1", 0x2E66, &options),
+            "input:     0.1\n\
+             format:    binary16 (1 sign, 5 exponent, 10 mantissa bits)\n\
+             decimal:   0.1\n\
+             exact:     0.0999755859375\n\
+             hex:       0x2E66\n\
+             binary:    0 01011 1001100110\n\
+             hex float: 0x1.998p-4\n\
+             sign:      0 (+)\n\
+             exponent:  01011 (biased 11, unbiased -4)\n\
+             mantissa:  1001100110 (0x266)\n\
+             class:     normal"
+        );
+        let options = Options {
+            output: OutputKind::Hex,
+            endianness: Endianness::Little,
+            ..Options::default()
+        };
+        assert_eq!(render("0.1", 0x3DCCCCCD, &options), "0xCDCCCC3D");
+        let options = Options {
+            output: OutputKind::Html,
+            ..Options::default()
+        };
+        let html = render("0.1", 0x3DCCCCCD, &options);
+        assert!(html.starts_with("<figure class=\"ieee754-diagram\">\n<svg "));
+        assert!(html.ends_with("<figcaption>binary32 0x3DCCCCCD = 0.1</figcaption>\n</figure>"));
+    }
+}
@@ -0,0 +1,170 @@
+mod convert;
+mod options;
+mod table;
+mod tui;
+
+use std::io::{BufRead, Write};
+
+use crate::cli::options::Options;
+
+const USAGE: &str = "\
+Usage: ieee-754 [OPTIONS] [VALUE]...
+       ieee-754 explain [OPTIONS] [VALUE]...
+       ieee-754 table [OPTIONS] [FILE]
+       ieee-754 tui [OPTIONS] [VALUE]
+
+Converts between decimal, hexadecimal and binary representations of IEEE 754
+values and prints a breakdown of their fields. Values are read from standard
+input, one per line, when none are given. `explain` prints the conversion
+step by step instead: encoding for decimal values, decoding for bit patterns.
+
+Values:
+  1.5, -2e-3, inf, nan      decimal (rounded with --rounding)
+  0x1.8p+0                  hexadecimal float
+  0x3FC00000, 0b0_0111...   bit pattern in hex or binary
+
+Options:
+  -f, --format <FORMAT>       binary16, bfloat16, binary32 (default), binary64
+                              or e<exponent bits>m<mantissa bits>, e.g. e4m3,
+                              with an fn suffix for the FP8 style layout
+                              without infinities, e.g. e4m3fn
+  -i, --input <KIND>          auto (default), decimal, hex, binary
+  -t, --to <KIND>             all (default), decimal, hex, binary, a
+                              diagram as svg or html, or precision for the
+                              error of storing a decimal value
+  -e, --endianness <ORDER>    big (default) or little, for hex and binary
+  -r, --rounding <MODE>       nearest-even (default), nearest-away,
+                              toward-zero, toward-positive, toward-negative
+  -m, --markdown              render `explain` traces as Markdown
+  -h, --help                  print this help
+
+Run `ieee-754 table --help` for decoding binary files and `ieee-754 tui --help`
+for editing bits interactively.
+";
+
+/// Runs the command line tool and returns the process exit code: 0 on
+/// success, 1 when a value could not be converted and 2 on usage errors.
+pub fn run(
+    args: &[String],
+    stdin: &mut dyn BufRead,
+    stdout: &mut dyn Write,
+    stderr: &mut dyn Write,
+) -> i32 {
+    if args.first().is_some_and(|arg| arg == "table") {
+        return table::run(&args[1..], stdin, stdout, stderr);
+    }
+    if args.first().is_some_and(|arg| arg == "tui") {
+        return tui::run(&args[1..], stdin, stdout, stderr);
+    }
+    let (explain, args) = match args.first() {
+        Some(arg) if arg == "explain" => (true, &args[1..]),
+        _ => (false, args),
+    };
+    let options = match Options::parse(args) {
+        Ok(options) => Options { explain, ..options },
+        Err(message) => {
+            let _ = writeln!(stderr, "error: {}\n\n{}", message, USAGE);
+            return 2;
+        }
+    };
+    if options.help {
+        let _ = write!(stdout, "{}", USAGE);
+        return 0;
+    }
+
+    let values: Vec<String> = if options.values.is_empty() {
+        stdin
+            .lines()
+            .map_while(Result::ok)
+            .filter(|line| !line.trim().is_empty())
+            .collect()
+    } else {
+        options.values.clone()
+    };
+    // Multi-line outputs end in a newline and are separated by blank lines.
+    let block = options.explain || options.output == options::OutputKind::Precision;
+    let mut code = 0;
+    let mut first = true;
+    for value in &values {
+        let output = if options.explain {
+            convert::explain(value, &options)
+        } else if options.output == options::OutputKind::Precision {
+            convert::precision(value, &options)
+        } else {
+            convert::parse_value(value, &options).map(|bits| convert::render(value, bits, &options))
+        };
+        match output {
+            Ok(output) => {
+                if !first && (block || options.output == options::OutputKind::All) {
+                    let _ = writeln!(stdout);
+                }
+                first = false;
+                let _ = write!(stdout, "{}", output);
+                if !block {
+                    let _ = writeln!(stdout);
+                }
+            }
+            Err(error) => {
+                let _ = writeln!(stderr, "error: {}: {}", value.trim(), error);
+                code = 1;
+            }
+        }
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_with(args: &[&str], stdin: &str) -> (i32, String, String) {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let code = run(&args, &mut stdin.as_bytes(), &mut stdout, &mut stderr);
+        (
+            code,
+            String::from_utf8(stdout).unwrap(),
+            String::from_utf8(stderr).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_run() {
+        let (code, stdout, _) = run_with(&["-t", "hex", "-f", "f64", "0.1", "-2"], "");
+        assert_eq!(code, 0);
+        assert_eq!(stdout, "0x3FB999999999999A\n0xC000000000000000\n");
+
+        let (code, stdout, stderr) =
+            run_with(&["--to=decimal", "-f", "half"], "0x3C00\n\n0xZZ\n0b1\n");
+        assert_eq!(code, 1);
+        assert_eq!(stdout, "1\n");
+        assert_eq!(
+            stderr,
+            "error: 0xZZ: unexpected character 'Z' at position 2\n\
+             error: 0b1: expected 16 digits, found 1\n"
+        );
+
+        let (code, stdout, _) = run_with(&["-f", "half", "1", "-inf"], "");
+        assert_eq!(code, 0);
+        assert!(stdout.contains("class:     normal\n\ninput:     -inf\n"));
+        assert!(stdout.ends_with("class:     infinite\n"));
+
+        let (code, _, stderr) = run_with(&["--rounding", "sideways"], "");
+        assert_eq!(code, 2);
+        assert!(stderr.starts_with("error: unknown rounding mode sideways\n\nUsage:"));
+        assert_eq!(run_with(&["-h"], "").1, USAGE);
+
+        let (code, stdout, _) = run_with(&["explain", "-f", "half", "-m", "2.5", "0x4100"], "");
+        assert_eq!(code, 0);
+        assert!(stdout.starts_with("## Encoding 2.5 as binary16\n"));
+        assert!(stdout.contains("\n\n## Decoding 0x4100 as binary16\n"));
+        assert!(stdout.ends_with("\nResult: `2.5`\n"));
+
+        let (code, stdout, _) = run_with(&["-t", "precision", "-f", "half", "0.1", "2049"], "");
+        assert_eq!(code, 0);
+        assert!(stdout.starts_with("Precision of 0.1 as binary16\n"));
+        assert!(stdout.contains("ulp error:       -0.400\n\nPrecision of 2049 as binary16\n"));
+        assert!(stdout.ends_with("ulp error:       -0.500\n"));
+    }
+}
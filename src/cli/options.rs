@@ -0,0 +1,225 @@
+use ieee_754::ieee754::{Endianness, Format, RoundingMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputKind {
+    /// `0x...` is a hex bit pattern unless it carries a `.` or `p`, in which
+    /// case it is a hexadecimal float; `0b...` is a binary bit pattern and
+    /// anything else is decimal.
+    #[default]
+    Auto,
+    Decimal,
+    Hex,
+    Binary,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputKind {
+    #[default]
+    All,
+    Decimal,
+    Hex,
+    Binary,
+    /// Bit layout diagram, see [`ieee_754::ieee754::Diagram`].
+    Svg,
+    Html,
+    /// Precision loss of a decimal input, see
+    /// [`ieee_754::ieee754::PrecisionReport`].
+    Precision,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub format: Format,
+    pub input: InputKind,
+    pub output: OutputKind,
+    pub endianness: Endianness,
+    pub rounding: RoundingMode,
+    pub values: Vec<String>,
+    /// Print a step by step trace instead of the field breakdown.
+    pub explain: bool,
+    pub markdown: bool,
+    pub help: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            format: Format::BINARY32,
+            input: InputKind::Auto,
+            output: OutputKind::All,
+            endianness: Endianness::Big,
+            rounding: RoundingMode::NearestTiesToEven,
+            values: Vec::new(),
+            explain: false,
+            markdown: false,
+            help: false,
+        }
+    }
+}
+
+impl Options {
+    pub fn parse(args: &[String]) -> Result<Options, String> {
+        let mut options = Options::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                options.values.extend(args.by_ref().cloned());
+                break;
+            }
+            if !arg.starts_with('-') || arg == "-" || is_number(arg) {
+                options.values.push(arg.clone());
+                continue;
+            }
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            if name == "-h" || name == "--help" {
+                options.help = true;
+                continue;
+            }
+            if name == "-m" || name == "--markdown" {
+                options.markdown = true;
+                continue;
+            }
+            let value = match inline.or_else(|| args.next().cloned()) {
+                Some(value) => value,
+                None => return Err(format!("missing value for {}", name)),
+            };
+            match name {
+                "-f" | "--format" => options.format = parse_format(&value)?,
+                "-i" | "--input" => options.input = parse_input(&value)?,
+                "-t" | "--to" => options.output = parse_output(&value)?,
+                "-e" | "--endianness" => options.endianness = parse_endianness(&value)?,
+                "-r" | "--rounding" => options.rounding = parse_rounding(&value)?,
+                _ => return Err(format!("unknown option {}", name)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Accepts the named formats as well as custom ones written `e<bits>m<bits>`
+/// such as `e4m3`, with an `fn` suffix for formats without infinities.
+pub fn parse_format(name: &str) -> Result<Format, String> {
+    let lowercase = name.to_ascii_lowercase();
+    let (lowercase, finite) = match lowercase.strip_suffix("fn") {
+        Some(rest) => (rest.to_string(), true),
+        None => (lowercase, false),
+    };
+    match (lowercase.as_str(), finite) {
+        ("binary16" | "half" | "f16" | "fp16", false) => return Ok(Format::BINARY16),
+        ("bfloat16" | "bf16", false) => return Ok(Format::BFLOAT16),
+        ("binary32" | "single" | "f32" | "fp32", false) => return Ok(Format::BINARY32),
+        ("binary64" | "double" | "f64" | "fp64", false) => return Ok(Format::BINARY64),
+        _ => {}
+    }
+    let custom = lowercase
+        .strip_prefix('e')
+        .and_then(|rest| rest.split_once('m'))
+        .and_then(|(exponent, mantissa)| Some((exponent.parse().ok()?, mantissa.parse().ok()?)));
+    match custom {
+        Some((exponent_bits, mantissa_bits)) if finite => {
+            Format::new_finite(exponent_bits, mantissa_bits).map_err(|error| error.to_string())
+        }
+        Some((exponent_bits, mantissa_bits)) => {
+            Format::new(exponent_bits, mantissa_bits).map_err(|error| error.to_string())
+        }
+        None => Err(format!("unknown format {}", name)),
+    }
+}
+
+fn parse_input(value: &str) -> Result<InputKind, String> {
+    match value {
+        "auto" => Ok(InputKind::Auto),
+        "decimal" | "dec" => Ok(InputKind::Decimal),
+        "hex" => Ok(InputKind::Hex),
+        "binary" | "bin" => Ok(InputKind::Binary),
+        _ => Err(format!("unknown input kind {}", value)),
+    }
+}
+
+fn parse_output(value: &str) -> Result<OutputKind, String> {
+    match value {
+        "all" => Ok(OutputKind::All),
+        "decimal" | "dec" => Ok(OutputKind::Decimal),
+        "hex" => Ok(OutputKind::Hex),
+        "binary" | "bin" => Ok(OutputKind::Binary),
+        "svg" => Ok(OutputKind::Svg),
+        "html" => Ok(OutputKind::Html),
+        "precision" => Ok(OutputKind::Precision),
+        _ => Err(format!("unknown output kind {}", value)),
+    }
+}
+
+pub fn parse_endianness(value: &str) -> Result<Endianness, String> {
+    match value {
+        "big" | "be" => Ok(Endianness::Big),
+        "little" | "le" => Ok(Endianness::Little),
+        _ => Err(format!("unknown endianness {}", value)),
+    }
+}
+
+fn parse_rounding(value: &str) -> Result<RoundingMode, String> {
+    match value {
+        "nearest-even" | "even" => Ok(RoundingMode::NearestTiesToEven),
+        "nearest-away" | "away" => Ok(RoundingMode::NearestTiesToAway),
+        "toward-zero" | "zero" => Ok(RoundingMode::TowardZero),
+        "toward-positive" | "up" => Ok(RoundingMode::TowardPositive),
+        "toward-negative" | "down" => Ok(RoundingMode::TowardNegative),
+        _ => Err(format!("unknown rounding mode {}", value)),
+    }
+}
+
+/// Negative values such as `-1.5` or `-inf` are values, not options.
+pub fn is_number(arg: &str) -> bool {
+    let rest = &arg[1..];
+    rest.starts_with(|character: char| character.is_ascii_digit() || character == '.')
+        || ["inf", "infinity", "nan"].contains(&rest.to_ascii_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &[&str]) -> Vec<String> {
+        input.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = Options::parse(&args(&[
+            "-f",
+            "bf16",
+            "--endianness=little",
+            "-1.5",
+            "--rounding",
+            "toward-zero",
+            "0x3C00",
+            "--",
+            "--5",
+        ]))
+        .unwrap();
+        assert_eq!(options.format, Format::BFLOAT16);
+        assert_eq!(options.endianness, Endianness::Little);
+        assert_eq!(options.rounding, RoundingMode::TowardZero);
+        assert_eq!(options.values, args(&["-1.5", "0x3C00", "--5"]));
+
+        assert_eq!(
+            parse_format("E4M3"),
+            Format::new(4, 3).map_err(|_| String::new())
+        );
+        assert_eq!(parse_format("e5m10").unwrap().to_string(), "binary16");
+        assert!(parse_format("e16m3").is_err());
+        assert_eq!(parse_format("e4m3fn"), Ok(Format::E4M3FN));
+        assert!(parse_format("binary32fn").is_err());
+        assert_eq!(
+            Options::parse(&args(&["--format"])),
+            Err("missing value for --format".to_string())
+        );
+        assert_eq!(
+            Options::parse(&args(&["-x", "1"])),
+            Err("unknown option -x".to_string())
+        );
+    }
+}
@@ -0,0 +1,388 @@
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+
+use ieee_754::ieee754::{
+    Batch, Class, DecimalFormatter, Endianness, FloatReader, Format, FormatOptions,
+};
+
+use crate::cli::options::{parse_endianness, parse_format};
+
+pub const USAGE: &str = "\
+Usage: ieee-754 table [OPTIONS] [FILE]
+
+Decodes FILE (standard input when missing or -) as a sequence of fixed-width
+values and prints one row per value. NaN, infinite and subnormal entries are
+flagged.
+
+Options:
+  -f, --format <FORMAT>       value format, binary32 by default
+  -e, --endianness <ORDER>    big (default) or little
+      --offset <BYTES>        bytes to skip before the first value
+      --stride <BYTES>        distance between values, the value width by default
+  -n, --count <COUNT>         stop after COUNT values
+  -o, --output <KIND>         table (default), csv or json
+  -h, --help                  print this help
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Output {
+    #[default]
+    Table,
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableOptions {
+    pub format: Format,
+    pub endianness: Endianness,
+    pub offset: usize,
+    pub stride: Option<usize>,
+    pub count: Option<usize>,
+    pub output: Output,
+    pub path: Option<String>,
+    pub help: bool,
+}
+
+impl Default for TableOptions {
+    fn default() -> TableOptions {
+        TableOptions {
+            format: Format::BINARY32,
+            endianness: Endianness::Big,
+            offset: 0,
+            stride: None,
+            count: None,
+            output: Output::Table,
+            path: None,
+            help: false,
+        }
+    }
+}
+
+impl TableOptions {
+    pub fn parse(args: &[String]) -> Result<TableOptions, String> {
+        let mut options = TableOptions::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if !arg.starts_with('-') || arg == "-" {
+                if options.path.is_some() {
+                    return Err(format!("unexpected argument {}", arg));
+                }
+                options.path = Some(arg.clone());
+                continue;
+            }
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            if name == "-h" || name == "--help" {
+                options.help = true;
+                continue;
+            }
+            let value = match inline.or_else(|| args.next().cloned()) {
+                Some(value) => value,
+                None => return Err(format!("missing value for {}", name)),
+            };
+            match name {
+                "-f" | "--format" => options.format = parse_format(&value)?,
+                "-e" | "--endianness" => options.endianness = parse_endianness(&value)?,
+                "--offset" => options.offset = parse_size(&value)?,
+                "--stride" => options.stride = Some(parse_size(&value)?),
+                "-n" | "--count" => options.count = Some(parse_size(&value)?),
+                "-o" | "--output" => {
+                    options.output = match value.as_str() {
+                        "table" => Output::Table,
+                        "csv" => Output::Csv,
+                        "json" => Output::Json,
+                        _ => return Err(format!("unknown output {}", value)),
+                    }
+                }
+                _ => return Err(format!("unknown option {}", name)),
+            }
+        }
+        let width = Batch::width(options.format);
+        if options.stride.is_some_and(|stride| stride < width) {
+            return Err(format!(
+                "stride must be at least the value width of {} bytes",
+                width
+            ));
+        }
+        Ok(options)
+    }
+}
+
+/// Decimal or `0x` prefixed hexadecimal byte count.
+fn parse_size(value: &str) -> Result<usize, String> {
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|_| format!("invalid size {}", value))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub index: usize,
+    pub offset: usize,
+    pub bits: u64,
+}
+
+/// Reads rows from `reader` one value at a time. Decoding errors, such as a
+/// truncated value at the end of the input, come back alongside the offset
+/// they occurred at.
+pub fn decode<R: Read>(mut reader: R, options: &TableOptions) -> (Vec<Row>, Vec<(usize, String)>) {
+    let width = Batch::width(options.format);
+    let stride = options.stride.unwrap_or(width);
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    let mut offset = options.offset;
+    if let Err(error) = skip(&mut reader, offset) {
+        errors.push((0, error.to_string()));
+        return (rows, errors);
+    }
+    let mut values = FloatReader::new(reader, options.format, options.endianness);
+    let mut index = 0;
+    while options.count.is_none_or(|count| index < count) {
+        match values.next() {
+            Some(Ok(bits)) => rows.push(Row {
+                index,
+                offset,
+                bits,
+            }),
+            Some(Err(error)) => errors.push((offset, error.to_string())),
+            None => break,
+        }
+        index += 1;
+        match offset.checked_add(stride) {
+            Some(next) => offset = next,
+            None => break,
+        }
+        if let Err(error) = skip(values.get_mut(), stride - width) {
+            errors.push((offset, error.to_string()));
+            break;
+        }
+    }
+    (rows, errors)
+}
+
+/// Discards up to `count` bytes, stopping early at end of input.
+fn skip<R: Read>(reader: &mut R, count: usize) -> io::Result<()> {
+    io::copy(&mut reader.take(count as u64), &mut io::sink()).map(|_| ())
+}
+
+fn flag(class: Class) -> &'static str {
+    match class {
+        Class::QuietNaN => "nan",
+        Class::SignalingNaN => "snan",
+        Class::Infinite => "inf",
+        Class::Subnormal => "subnormal",
+        Class::Zero | Class::Normal => "",
+    }
+}
+
+pub fn render(rows: &[Row], options: &TableOptions) -> String {
+    let format = options.format;
+    let hex = FormatOptions::new().prefix(true);
+    let cells: Vec<[String; 5]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.index.to_string(),
+                row.offset.to_string(),
+                hex.format_hex(format, row.bits),
+                DecimalFormatter::shortest(format, row.bits),
+                flag(format.classify(row.bits)).to_string(),
+            ]
+        })
+        .collect();
+    match options.output {
+        Output::Table => render_table(rows, &cells, format),
+        Output::Csv => {
+            let mut output = String::from("index,offset,hex,value,flag\n");
+            for cell in &cells {
+                output.push_str(&cell.join(","));
+                output.push('\n');
+            }
+            output
+        }
+        Output::Json => {
+            let entries: Vec<String> = rows
+                .iter()
+                .zip(&cells)
+                .map(|(row, cell)| {
+                    let value = if format.is_finite(row.bits) {
+                        cell[3].clone()
+                    } else {
+                        "null".to_string()
+                    };
+                    format!(
+                        "  {{\"index\": {}, \"offset\": {}, \"hex\": \"{}\", \"value\": {}, \"class\": \"{}\"}}",
+                        cell[0],
+                        cell[1],
+                        cell[2],
+                        value,
+                        format.classify(row.bits)
+                    )
+                })
+                .collect();
+            if entries.is_empty() {
+                "[]\n".to_string()
+            } else {
+                format!("[\n{}\n]\n", entries.join(",\n"))
+            }
+        }
+    }
+}
+
+fn render_table(rows: &[Row], cells: &[[String; 5]], format: Format) -> String {
+    let header = ["index", "offset", "hex", "value", "flag"];
+    let mut widths = header.map(str::len);
+    for cell in cells {
+        for (width, text) in widths.iter_mut().zip(cell) {
+            *width = (*width).max(text.len());
+        }
+    }
+    let line = |cell: [&str; 5]| {
+        let text: Vec<String> = cell
+            .iter()
+            .zip(widths)
+            .map(|(text, width)| format!("{:<width$}", text, width = width))
+            .collect();
+        format!("{}\n", text.join("  ").trim_end())
+    };
+    let mut output = line(header);
+    for cell in cells {
+        output.push_str(&line(cell.each_ref().map(String::as_str)));
+    }
+    let count = |class: &[Class]| {
+        rows.iter()
+            .filter(|row| class.contains(&format.classify(row.bits)))
+            .count()
+    };
+    output.push_str(&format!(
+        "{} values, {} NaN, {} infinite, {} subnormal\n",
+        rows.len(),
+        count(&[Class::QuietNaN, Class::SignalingNaN]),
+        count(&[Class::Infinite]),
+        count(&[Class::Subnormal])
+    ));
+    output
+}
+
+pub fn run(
+    args: &[String],
+    stdin: &mut dyn Read,
+    stdout: &mut dyn Write,
+    stderr: &mut dyn Write,
+) -> i32 {
+    let options = match TableOptions::parse(args) {
+        Ok(options) => options,
+        Err(message) => {
+            let _ = writeln!(stderr, "error: {}\n\n{}", message, USAGE);
+            return 2;
+        }
+    };
+    if options.help {
+        let _ = write!(stdout, "{}", USAGE);
+        return 0;
+    }
+    let (rows, errors) = match options.path.as_deref() {
+        None | Some("-") => decode(BufReader::new(stdin), &options),
+        Some(path) => match File::open(path) {
+            Ok(file) => decode(BufReader::new(file), &options),
+            Err(error) => {
+                let _ = writeln!(stderr, "error: {}: {}", path, error);
+                return 1;
+            }
+        },
+    };
+    let _ = write!(stdout, "{}", render(&rows, &options));
+    for (offset, message) in &errors {
+        let _ = writeln!(stderr, "error: value at offset {}: {}", offset, message);
+    }
+    if errors.is_empty() {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &[&str]) -> Vec<String> {
+        input.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    const BYTES: [u8; 18] = [
+        0xAA, 0xAA, // header
+        0x00, 0x00, 0x80, 0x3F, // 1.0
+        0x00, 0x00, 0xC0, 0x7F, // NaN
+        0x00, 0x00, 0x80, 0xFF, // -inf
+        0x01, 0x00, 0x00, 0x00, // smallest subnormal
+    ];
+
+    #[test]
+    fn test_decode_rows() {
+        let options =
+            TableOptions::parse(&args(&["-e", "little", "--offset", "2", "-o", "csv"])).unwrap();
+        let (rows, errors) = decode(&BYTES[..], &options);
+        assert!(errors.is_empty());
+        assert_eq!(
+            render(&rows, &options),
+            "index,offset,hex,value,flag\n\
+             0,2,0x3F800000,1,\n\
+             1,6,0x7FC00000,NaN,nan\n\
+             2,10,0xFF800000,-inf,inf\n\
+             3,14,0x00000001,0.000000000000000000000000000000000000000000001,subnormal\n"
+        );
+
+        // Every second binary16 half of the little endian words.
+        let options = TableOptions::parse(&args(&[
+            "-f", "half", "-e", "little", "--offset", "0x4", "--stride", "4", "-n", "2",
+        ]))
+        .unwrap();
+        let (rows, errors) = decode(&BYTES[..], &options);
+        assert!(errors.is_empty());
+        assert_eq!(
+            render(&rows, &options),
+            "index  offset  hex     value  flag\n\
+             0      4       0x3F80  1.875\n\
+             1      8       0x7FC0  NaN    nan\n\
+             2 values, 1 NaN, 0 infinite, 0 subnormal\n"
+        );
+
+        let options = TableOptions::parse(&args(&["--offset", "4"])).unwrap();
+        let (rows, errors) = decode(&BYTES[..], &options);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(errors, vec![(16, "expected 32 bits, found 16".to_string())]);
+        assert!(TableOptions::parse(&args(&["--stride", "2"])).is_err());
+
+        let options =
+            TableOptions::parse(&args(&["--offset", "1", "--stride", "0xFFFFFFFFFFFFFFFF"]))
+                .unwrap();
+        let (rows, errors) = decode(&BYTES[..], &options);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].offset, 1);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_run_json() {
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let code = run(
+            &args(&["-o", "json", "-e", "little", "--offset=2", "-n=2"]),
+            &mut &BYTES[..],
+            &mut stdout,
+            &mut stderr,
+        );
+        assert_eq!(code, 0);
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "[\n  {\"index\": 0, \"offset\": 2, \"hex\": \"0x3F800000\", \"value\": 1, \"class\": \"normal\"},\n  \
+             {\"index\": 1, \"offset\": 6, \"hex\": \"0x7FC00000\", \"value\": null, \"class\": \"quiet NaN\"}\n]\n"
+        );
+    }
+}
@@ -0,0 +1,475 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+use ieee_754::ieee754::{Class, Format};
+use ieee_754::IEEE754;
+
+use crate::cli::convert;
+use crate::cli::options::{is_number, parse_format, Options};
+
+pub const USAGE: &str = "\
+Usage: ieee-754 tui [OPTIONS] [VALUE]
+
+Shows the bits of VALUE (0 by default) and lets you toggle them one by one
+while the decoded value, its class and its neighbours update.
+
+Keys:
+  left/right, h/l     move between bits
+  space, t            toggle the selected bit
+  up/down, k/j        step to the next or previous value
+  w                   switch between binary32 and binary64
+  q, esc              quit (esc acts once the next key arrives, since it may
+                      start an arrow key)
+
+Options:
+  -f, --format <FORMAT>    binary32 (default) or binary64
+      --keys <KEYS>        apply KEYS instead of reading the keyboard and
+                           print the final screen, e.g. --keys 'llt'
+  -h, --help               print this help
+
+Keys are also read from standard input when it is not a terminal.
+";
+
+const SIGN: &str = "\x1b[31m";
+const EXPONENT: &str = "\x1b[32m";
+const MANTISSA: &str = "\x1b[34m";
+const SELECTED: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Left,
+    Right,
+    Toggle,
+    Up,
+    Down,
+    Width,
+    Quit,
+}
+
+/// Decodes raw terminal input, including arrow key escape sequences.
+/// Unknown bytes are skipped.
+pub fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let key = match bytes[i] {
+            0x1b if i + 2 < bytes.len() && matches!(bytes[i + 1], b'[' | b'O') => {
+                i += 2;
+                match bytes[i] {
+                    b'A' => Some(Key::Up),
+                    b'B' => Some(Key::Down),
+                    b'C' => Some(Key::Right),
+                    b'D' => Some(Key::Left),
+                    _ => None,
+                }
+            }
+            0x1b | 0x03 | b'q' => Some(Key::Quit),
+            b'h' => Some(Key::Left),
+            b'l' => Some(Key::Right),
+            b'k' => Some(Key::Up),
+            b'j' => Some(Key::Down),
+            b' ' | b't' => Some(Key::Toggle),
+            b'w' => Some(Key::Width),
+            _ => None,
+        };
+        keys.extend(key);
+        i += 1;
+    }
+    keys
+}
+
+/// Length of the escape sequence that `bytes` ends in before its final
+/// byte arrived (`ESC` or `ESC [`), or 0. A terminal read can stop in the
+/// middle of an arrow key.
+pub fn unfinished_escape(bytes: &[u8]) -> usize {
+    match bytes {
+        [.., 0x1b] => 1,
+        [.., 0x1b, b'[' | b'O'] => 2,
+        _ => 0,
+    }
+}
+
+/// Bit pattern being edited. `cursor` counts from the sign bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Editor {
+    pub format: Format,
+    pub bits: u64,
+    pub cursor: u32,
+}
+
+impl Editor {
+    pub fn new(format: Format, bits: u64) -> Editor {
+        Editor {
+            format,
+            bits,
+            cursor: 0,
+        }
+    }
+
+    fn selected_mask(&self) -> u64 {
+        1 << (self.format.total_bits() - 1 - self.cursor)
+    }
+
+    /// Applies a key and returns false once the editor should close.
+    pub fn apply(&mut self, key: Key) -> bool {
+        let format = self.format;
+        match key {
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right => self.cursor = (self.cursor + 1).min(format.total_bits() - 1),
+            Key::Toggle => self.bits ^= self.selected_mask(),
+            Key::Up => self.bits = format.next_up(self.bits),
+            Key::Down => self.bits = format.next_down(self.bits),
+            Key::Width => {
+                *self = if format == Format::BINARY32 {
+                    let value = f32::from_bits(self.bits as u32) as f64;
+                    Editor::new(Format::BINARY64, value.to_bits())
+                } else {
+                    let value = f64::from_bits(self.bits) as f32;
+                    Editor::new(Format::BINARY32, value.to_bits() as u64)
+                };
+            }
+            Key::Quit => return false,
+        }
+        true
+    }
+
+    /// Draws the screen, with colored fields and the selected bit in
+    /// reverse video when `color` is set.
+    pub fn render(&self, color: bool) -> String {
+        let format = self.format;
+        let total = format.total_bits();
+        let mut layout = String::from("  ");
+        let mut caret = String::from("  ");
+        for index in 0..total {
+            let (field, style) = if index == 0 {
+                ("sign", SIGN)
+            } else if index <= format.exponent_bits() {
+                ("exponent", EXPONENT)
+            } else {
+                ("mantissa", MANTISSA)
+            };
+            if index == 1 || index == format.exponent_bits() + 1 {
+                layout.push(' ');
+                caret.push(' ');
+            }
+            let bit = if (self.bits >> (total - 1 - index)) & 1 == 1 {
+                '1'
+            } else {
+                '0'
+            };
+            let selected = index == self.cursor;
+            if color {
+                layout.push_str(style);
+                if selected {
+                    layout.push_str(SELECTED);
+                }
+                layout.push(bit);
+                layout.push_str(RESET);
+            } else {
+                layout.push(bit);
+            }
+            if selected {
+                caret.push('^');
+                caret.push_str(&format!(" bit {} ({})", total - 1 - index, field));
+            } else if index < self.cursor {
+                caret.push(' ');
+            }
+        }
+
+        let digits = (total / 4) as usize;
+        let mut screen = format!("ieee-754 tui  {}\n\n{}\n{}\n\n", format, layout, caret);
+        let rows = [
+            ("value", value_text(format, self.bits)),
+            ("hex", format!("0x{:0digits$X}", self.bits)),
+            ("class", format.classify(self.bits).to_string()),
+            ("previous", value_text(format, format.next_down(self.bits))),
+            ("next", value_text(format, format.next_up(self.bits))),
+        ];
+        for (label, value) in rows {
+            screen.push_str(&format!("  {:<9} {}\n", label, value));
+        }
+        screen.push_str("\n  h/l move  space toggles  k/j next/previous  w width  q quits\n");
+        screen
+    }
+}
+
+fn value_text(format: Format, bits: u64) -> String {
+    let decoded = if format == Format::BINARY32 {
+        IEEE754::decode_32bit((bits as u32).to_be_bytes()).map(|value| value.to_string())
+    } else {
+        IEEE754::decode_64bit(bits.to_be_bytes()).map(|value| value.to_string())
+    };
+    // The decoders reject the all-ones exponent.
+    decoded.unwrap_or_else(|_| match format.classify(bits) {
+        Class::Infinite if format.sign(bits) => "-inf".to_string(),
+        Class::Infinite => "inf".to_string(),
+        _ => "NaN".to_string(),
+    })
+}
+
+/// Switches the terminal to raw mode through `stty` and restores the saved
+/// settings when dropped.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> io::Result<RawMode> {
+        let output = Command::new("stty")
+            .arg("-g")
+            .stdin(Stdio::inherit())
+            .output()?;
+        let saved = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Command::new("stty")
+            .args(["raw", "-echo"])
+            .stdin(Stdio::inherit())
+            .status()?;
+        Ok(RawMode { saved })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = Command::new("stty")
+            .arg(&self.saved)
+            .stdin(Stdio::inherit())
+            .status();
+    }
+}
+
+fn interact(
+    editor: &mut Editor,
+    stdin: &mut dyn BufRead,
+    stdout: &mut dyn Write,
+) -> io::Result<()> {
+    let _raw = RawMode::enable()?;
+    write!(stdout, "\x1b[?25l")?;
+    let mut pending: Vec<u8> = Vec::new();
+    loop {
+        // Raw mode does not translate line feeds.
+        let screen = editor.render(true).replace('\n', "\r\n");
+        write!(stdout, "\x1b[H\x1b[2J{}", screen)?;
+        stdout.flush()?;
+        let buffer = stdin.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+        pending.extend_from_slice(buffer);
+        let length = buffer.len();
+        stdin.consume(length);
+        // Hold back a split escape sequence until the next read completes it.
+        let complete = pending.len() - unfinished_escape(&pending);
+        let keys = parse_keys(&pending[..complete]);
+        pending.drain(..complete);
+        if !keys.into_iter().all(|key| editor.apply(key)) {
+            break;
+        }
+    }
+    write!(stdout, "\x1b[?25h\r\n")?;
+    stdout.flush()
+}
+
+pub fn run(
+    args: &[String],
+    stdin: &mut dyn BufRead,
+    stdout: &mut dyn Write,
+    stderr: &mut dyn Write,
+) -> i32 {
+    let mut format = Format::BINARY32;
+    let mut keys: Option<String> = None;
+    let mut value: Option<String> = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, inline)) if arg.starts_with("--") => (name, Some(inline.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let parsed = match name {
+            "-h" | "--help" => {
+                let _ = write!(stdout, "{}", USAGE);
+                return 0;
+            }
+            "-f" | "--format" | "--keys" => match inline.or_else(|| args.next().cloned()) {
+                None => Err(format!("missing value for {}", name)),
+                Some(argument) if name == "--keys" => {
+                    keys = Some(argument);
+                    Ok(())
+                }
+                Some(argument) => parse_format(&argument).and_then(|parsed| {
+                    if parsed == Format::BINARY32 || parsed == Format::BINARY64 {
+                        format = parsed;
+                        Ok(())
+                    } else {
+                        Err(format!(
+                            "unsupported format {}, use binary32 or binary64",
+                            parsed
+                        ))
+                    }
+                }),
+            },
+            _ if value.is_none() && (!arg.starts_with('-') || is_number(arg)) => {
+                value = Some(arg.clone());
+                Ok(())
+            }
+            _ => Err(format!("unexpected argument {}", arg)),
+        };
+        if let Err(message) = parsed {
+            let _ = writeln!(stderr, "error: {}\n\n{}", message, USAGE);
+            return 2;
+        }
+    }
+
+    let options = Options {
+        format,
+        ..Options::default()
+    };
+    let bits = match value
+        .as_deref()
+        .map(|value| convert::parse_value(value, &options))
+    {
+        None => 0,
+        Some(Ok(bits)) => bits,
+        Some(Err(error)) => {
+            let _ = writeln!(stderr, "error: {}: {}", value.unwrap_or_default(), error);
+            return 1;
+        }
+    };
+    let mut editor = Editor::new(format, bits);
+
+    if keys.is_none() && io::stdin().is_terminal() {
+        return match interact(&mut editor, stdin, stdout) {
+            Ok(()) => 0,
+            Err(error) => {
+                let _ = writeln!(stderr, "error: {}", error);
+                1
+            }
+        };
+    }
+    let script = match keys {
+        Some(keys) => keys.into_bytes(),
+        None => {
+            let mut script = Vec::new();
+            if let Err(error) = stdin.read_to_end(&mut script) {
+                let _ = writeln!(stderr, "error: -: {}", error);
+                return 1;
+            }
+            script
+        }
+    };
+    for key in parse_keys(&script) {
+        if !editor.apply(key) {
+            break;
+        }
+    }
+    let _ = write!(stdout, "{}", editor.render(false));
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &[&str]) -> Vec<String> {
+        input.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(
+            parse_keys(b"\x1b[C\x1b[D\x1bOAjx tw\x1b"),
+            vec![
+                Key::Right,
+                Key::Left,
+                Key::Up,
+                Key::Down,
+                Key::Toggle,
+                Key::Toggle,
+                Key::Width,
+                Key::Quit
+            ]
+        );
+
+        assert_eq!(unfinished_escape(b"j\x1b"), 1);
+        assert_eq!(unfinished_escape(b"\x1b["), 2);
+        assert_eq!(unfinished_escape(b"\x1bO"), 2);
+        assert_eq!(unfinished_escape(b"\x1b[C"), 0);
+        assert_eq!(unfinished_escape(b"["), 0);
+        // An arrow key split across two reads, joined again.
+        let mut pending = b"j\x1b".to_vec();
+        let complete = pending.len() - unfinished_escape(&pending);
+        assert_eq!(parse_keys(&pending[..complete]), vec![Key::Down]);
+        pending.drain(..complete);
+        pending.extend_from_slice(b"[C");
+        assert_eq!(unfinished_escape(&pending), 0);
+        assert_eq!(parse_keys(&pending), vec![Key::Right]);
+
+        let mut editor = Editor::new(Format::BINARY32, 0x3F800000);
+        for key in parse_keys(b"hllt") {
+            assert!(editor.apply(key));
+        }
+        // Clearing exponent bit 29 of 1.0 leaves 2^-64.
+        assert_eq!(editor.cursor, 2);
+        assert_eq!(editor.bits, 0x1F800000);
+        assert!(editor.apply(Key::Up));
+        assert_eq!(editor.bits, 0x1F800001);
+        assert!(editor.apply(Key::Width));
+        assert_eq!(editor.format, Format::BINARY64);
+        assert!(!editor.apply(Key::Quit));
+
+        let mut editor = Editor::new(Format::BINARY32, 0x80000000);
+        editor.apply(Key::Up);
+        assert_eq!(editor.bits, 0x00000001);
+        editor.apply(Key::Down);
+        editor.apply(Key::Down);
+        assert_eq!(editor.bits, 0x80000001);
+
+        let screen = Editor::new(Format::BINARY32, 0x3F800000).render(true);
+        assert!(screen.contains("\x1b[31m\x1b[7m0\x1b[0m \x1b[32m0\x1b[0m"));
+    }
+
+    #[test]
+    fn test_run_script() {
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let code = run(
+            &args(&["1", "--keys", "lllllllllt"]),
+            &mut &b""[..],
+            &mut stdout,
+            &mut stderr,
+        );
+        assert_eq!(code, 0);
+        assert_eq!(
+            String::from_utf8(stdout).unwrap(),
+            "ieee-754 tui  binary32\n\
+             \n  0 01111111 10000000000000000000000\
+             \n             ^ bit 22 (mantissa)\n\
+             \n  value     1.5\
+             \n  hex       0x3FC00000\
+             \n  class     normal\
+             \n  previous  1.4999999\
+             \n  next      1.5000001\n\
+             \n  h/l move  space toggles  k/j next/previous  w width  q quits\n"
+        );
+
+        let mut stdout = Vec::new();
+        let code = run(
+            &args(&["-f", "binary64", "inf"]),
+            &mut &b"kqk"[..],
+            &mut stdout,
+            &mut stderr,
+        );
+        assert_eq!(code, 0);
+        let screen = String::from_utf8(stdout).unwrap();
+        assert!(screen.contains("value     inf\n"));
+        assert!(screen.contains("previous  179769313486231570000000000000000"));
+
+        let code = run(
+            &args(&["-f", "half"]),
+            &mut &b""[..],
+            &mut Vec::new(),
+            &mut stderr,
+        );
+        assert_eq!(code, 2);
+    }
+}
@@ -0,0 +1,257 @@
+use crate::ieee754::format::Format;
+use core::fmt;
+use core::num::{ParseFloatError, ParseIntError};
+#[cfg(feature = "std")]
+use std::io;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+/// Errors of every operation in the crate. Marked `#[non_exhaustive]` as
+/// the variants depend on the enabled features (`Io` needs `std`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    ExponentAll1s,
+    MantissaAll0s,
+    InvalidBitLength {
+        expected: usize,
+        actual: usize,
+    },
+    InvalidBit {
+        offset: usize,
+        value: u8,
+    },
+    InvalidByte {
+        index: usize,
+        value: u32,
+    },
+    InvalidFormat {
+        exponent_bits: u32,
+        mantissa_bits: u32,
+    },
+    EmptyValues,
+    InvalidSignBit,
+    InvalidExponent,
+    InvalidMantissa,
+    InvalidMSBMantissa,
+    InvalidLSBMantissa,
+    EmptySignBit,
+    EmptyExponent,
+    EmptyMantissa,
+    MantissaOverflow {
+        length: usize,
+    },
+    InvalidIntegerPart,
+    InvalidFractionalPart,
+    UnexpectedCharacter {
+        position: usize,
+        character: char,
+    },
+    UnexpectedEnd {
+        position: usize,
+    },
+    InexactValue,
+    InvalidDigitCount {
+        expected: usize,
+        actual: usize,
+    },
+    BufferTooSmall {
+        needed: usize,
+        available: usize,
+    },
+    /// NaN payload that needs more than `bits` bits, or a zero payload for
+    /// a signaling NaN (it would encode infinity).
+    InvalidPayload {
+        payload: u64,
+        bits: u32,
+    },
+    /// A value of one format handed to something set up for another, such
+    /// as a `FloatWriter`.
+    FormatMismatch {
+        expected: Format,
+        actual: Format,
+    },
+    /// I/O failure of a stream.
+    #[cfg(feature = "std")]
+    Io(IoError),
+    ParseInt(ParseIntError),
+    ParseFloat(ParseFloatError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ExponentAll1s => write!(f, "exponent bits are all 1s (infinity or NaN)"),
+            Error::MantissaAll0s => write!(f, "mantissa bits are all 0s"),
+            Error::InvalidBitLength { expected, actual } => {
+                write!(f, "expected {} bits, found {}", expected, actual)
+            }
+            Error::InvalidBit { offset, value } => {
+                write!(f, "invalid bit value {} at bit offset {}", value, offset)
+            }
+            Error::InvalidByte { index, value } => {
+                write!(
+                    f,
+                    "value {:#x} at index {} does not fit in a byte",
+                    value, index
+                )
+            }
+            Error::InvalidFormat {
+                exponent_bits,
+                mantissa_bits,
+            } => write!(
+                f,
+                "unsupported format with {} exponent bits and {} mantissa bits",
+                exponent_bits, mantissa_bits
+            ),
+            Error::EmptyValues => write!(f, "no values given"),
+            Error::InvalidSignBit => write!(f, "invalid sign bit"),
+            Error::InvalidExponent => write!(f, "invalid exponent bits"),
+            Error::InvalidMantissa => write!(f, "invalid mantissa bits"),
+            Error::InvalidMSBMantissa => write!(f, "invalid integer part of the mantissa"),
+            Error::InvalidLSBMantissa => write!(f, "invalid fractional part of the mantissa"),
+            Error::EmptySignBit => write!(f, "missing sign bit"),
+            Error::EmptyExponent => write!(f, "missing exponent bits"),
+            Error::EmptyMantissa => write!(f, "missing mantissa bits"),
+            Error::MantissaOverflow { length } => {
+                write!(f, "rounding overflows the {} bit mantissa", length)
+            }
+            Error::InvalidIntegerPart => write!(f, "invalid floating integer part value"),
+            Error::InvalidFractionalPart => write!(f, "invalid floating fractional part value"),
+            Error::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "unexpected character {:?} at position {}",
+                character, position
+            ),
+            Error::UnexpectedEnd { position } => {
+                write!(f, "unexpected end of input at position {}", position)
+            }
+            Error::InexactValue => write!(f, "value is not exactly representable"),
+            Error::InvalidDigitCount { expected, actual } => {
+                write!(f, "expected {} digits, found {}", expected, actual)
+            }
+            Error::BufferTooSmall { needed, available } => write!(
+                f,
+                "output holds {} elements but {} are needed",
+                available, needed
+            ),
+            Error::InvalidPayload { payload, bits } => write!(
+                f,
+                "invalid NaN payload {:#x} for {} payload bits",
+                payload, bits
+            ),
+            Error::FormatMismatch { expected, actual } => {
+                write!(f, "expected {} values, found {}", expected, actual)
+            }
+            #[cfg(feature = "std")]
+            Error::Io(error) => write!(f, "i/o error: {}", error.get_ref()),
+            Error::ParseInt(error) => write!(f, "unable to parse integer: {}", error),
+            Error::ParseFloat(error) => write!(f, "unable to parse float: {}", error),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::ParseInt(error) => Some(error),
+            Error::ParseFloat(error) => Some(error),
+            #[cfg(feature = "std")]
+            Error::Io(error) => Some(error.get_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(error: ParseIntError) -> Error {
+        Error::ParseInt(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(IoError(Arc::new(error)))
+    }
+}
+
+/// The original `io::Error` of [`Error::Io`], shared so `Error` stays
+/// cloneable. Errors compare equal by kind and message.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct IoError(Arc<io::Error>);
+
+#[cfg(feature = "std")]
+impl IoError {
+    pub fn kind(&self) -> io::ErrorKind {
+        self.0.kind()
+    }
+
+    pub fn get_ref(&self) -> &io::Error {
+        &self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for IoError {
+    fn eq(&self, other: &IoError) -> bool {
+        self.kind() == other.kind() && self.0.to_string() == other.0.to_string()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for IoError {}
+
+impl From<ParseFloatError> for Error {
+    fn from(error: ParseFloatError) -> Error {
+        Error::ParseFloat(error)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_error_display_and_source() {
+        let error = Error::InvalidBitLength {
+            expected: 32,
+            actual: 24,
+        };
+        assert_eq!(error.to_string(), "expected 32 bits, found 24");
+        assert!(error.source().is_none());
+
+        let error: Error = "1.x".parse::<f32>().unwrap_err().into();
+        assert_eq!(
+            error.to_string(),
+            "unable to parse float: invalid float literal"
+        );
+        assert!(error.source().is_some());
+
+        fn question_mark() -> Result<f32, Box<dyn std::error::Error>> {
+            let value = crate::IEEE754::from_32bit_hex("C2 05 5Z 55")?;
+            Ok(value)
+        }
+        assert_eq!(
+            question_mark().unwrap_err().to_string(),
+            "unexpected character 'Z' at position 7"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_error_source() {
+        let inner = io::Error::new(io::ErrorKind::UnexpectedEof, "stream ended");
+        let error = Error::from(inner);
+        assert_eq!(error.to_string(), "i/o error: stream ended");
+        let source = error.source().unwrap();
+        let source = source.downcast_ref::<io::Error>().unwrap();
+        assert_eq!(source.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(error.clone(), error);
+    }
+}
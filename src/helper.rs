@@ -1,8 +1,46 @@
+use crate::error::Error;
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+#[derive(Debug)]
+pub struct PackBits;
+
+impl PackBits {
+    /// Packs a slice of 0/1 digits, most significant first, into an integer.
+    pub fn pack(binaries: &[u8]) -> Result<u64, Error> {
+        if binaries.len() > 64 {
+            return Err(Error::InvalidBitLength {
+                expected: 64,
+                actual: binaries.len(),
+            });
+        }
+        let mut value: u64 = 0;
+        for (offset, bit) in binaries.iter().enumerate() {
+            if *bit > 1 {
+                return Err(Error::InvalidBit {
+                    offset,
+                    value: *bit,
+                });
+            }
+            value = (value << 1) | *bit as u64;
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
 #[derive(Debug)]
 pub struct ComputeMantissaBits;
 
+#[cfg(feature = "alloc")]
 impl ComputeMantissaBits {
-    pub fn round_up(bits: &mut Vec<u8>) -> Result<Vec<u8>, String> {
+    pub fn round_up(bits: &mut Vec<u8>) -> Result<Vec<u8>, Error> {
         let mut is_overflow: bool = true;
         let mut i = bits.len() - 1;
 
@@ -16,7 +54,7 @@ impl ComputeMantissaBits {
                     break;
                 }
             } else {
-                return Err("unable to round up, no bit to compare".to_string());
+                return Err(Error::EmptyMantissa);
             }
             if i == 0 {
                 break;
@@ -24,12 +62,12 @@ impl ComputeMantissaBits {
             i -= 1;
         }
         if is_overflow {
-            return Err("unable to round up, overflow".to_string());
+            return Err(Error::MantissaOverflow { length: bits.len() });
         }
         Ok(bits.to_owned())
     }
 
-    pub fn compute(input: Vec<u8>, length: usize) -> Result<Vec<u8>, String> {
+    pub fn compute(input: Vec<u8>, length: usize) -> Result<Vec<u8>, Error> {
         let mut bits = input.get(0..length).unwrap().to_vec();
         let guard_bit: u8 = *input.get(length).unwrap_or(&0);
         let round_bit: u8 = *input.get(length + 1).unwrap_or(&0);
@@ -39,13 +77,12 @@ impl ComputeMantissaBits {
             if round_bit == 1 {
                 is_round_up = true;
             } else if round_bit == 0 {
-                if sticky_bits
+                if !sticky_bits
                     .clone()
                     .into_iter()
                     .filter(|v| *v == 1)
                     .collect::<Vec<u8>>()
-                    .len()
-                    > 0
+                    .is_empty()
                 {
                     is_round_up = true;
                 } else {
@@ -64,10 +101,12 @@ impl ComputeMantissaBits {
     }
 }
 
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone)]
 pub struct SplitFloat;
+#[cfg(feature = "alloc")]
 impl SplitFloat {
-    pub fn f32(input: f32) -> Result<(u8, u32, f32), String> {
+    pub fn f32(input: f32) -> Result<(u8, u32, f32), Error> {
         let sign: u8 = if input.is_sign_negative() { 1 } else { 0 };
         let value: String = input.to_string().replace("-", "");
         let vsplitted: Vec<&str> = if !value.contains(".") {
@@ -75,25 +114,25 @@ impl SplitFloat {
         } else {
             value.split(".").collect()
         };
-        let integer_part: u32 = if let Some(v) = vsplitted.get(0) {
+        let integer_part: u32 = if let Some(v) = vsplitted.first() {
             match v.parse::<u32>() {
                 Ok(v) => v,
-                Err(error) => return Err(error.to_string()),
+                Err(error) => return Err(Error::from(error)),
             }
         } else {
-            return Err("invalid floating integer part value".to_string());
+            return Err(Error::InvalidIntegerPart);
         };
         let fractional_part: f32 = if let Some(v) = vsplitted.get(1) {
             match format!("0.{}", v).parse::<f32>() {
                 Ok(v) => v,
-                Err(error) => return Err(error.to_string()),
+                Err(error) => return Err(Error::from(error)),
             }
         } else {
-            return Err("invalid floating fractional part value".to_string());
+            return Err(Error::InvalidFractionalPart);
         };
         Ok((sign, integer_part, fractional_part))
     }
-    pub fn f64(input: f64) -> Result<(u8, u64, f64), String> {
+    pub fn f64(input: f64) -> Result<(u8, u64, f64), Error> {
         let sign: u8 = if input.is_sign_negative() { 1 } else { 0 };
         let value: String = input.to_string().replace("-", "");
         let vsplitted: Vec<&str> = if !value.contains(".") {
@@ -101,21 +140,21 @@ impl SplitFloat {
         } else {
             value.split(".").collect()
         };
-        let integer_part: u64 = if let Some(v) = vsplitted.get(0) {
+        let integer_part: u64 = if let Some(v) = vsplitted.first() {
             match v.parse::<u64>() {
                 Ok(v) => v,
-                Err(error) => return Err(error.to_string()),
+                Err(error) => return Err(Error::from(error)),
             }
         } else {
-            return Err("invalid floating integer part value".to_string());
+            return Err(Error::InvalidIntegerPart);
         };
         let fractional_part: f64 = if let Some(v) = vsplitted.get(1) {
             match format!("0.{}", v).parse::<f64>() {
                 Ok(v) => v,
-                Err(error) => return Err(error.to_string()),
+                Err(error) => return Err(Error::from(error)),
             }
         } else {
-            return Err("invalid floating fractional part value".to_string());
+            return Err(Error::InvalidFractionalPart);
         };
         Ok((sign, integer_part, fractional_part))
     }
@@ -0,0 +1,677 @@
+use crate::ieee754::format::Format;
+use crate::ieee754::rounding::{Rounded, RoundingMode};
+
+/// IEEE 754 exception flags raised by an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Flags {
+    pub invalid: bool,
+    pub division_by_zero: bool,
+    pub overflow: bool,
+    pub underflow: bool,
+    pub inexact: bool,
+}
+
+impl Flags {
+    pub const fn any(&self) -> bool {
+        self.invalid || self.division_by_zero || self.overflow || self.underflow || self.inexact
+    }
+
+    /// Flags raised by either operation, for accumulating over a sequence.
+    pub const fn union(&self, other: Flags) -> Flags {
+        Flags {
+            invalid: self.invalid || other.invalid,
+            division_by_zero: self.division_by_zero || other.division_by_zero,
+            overflow: self.overflow || other.overflow,
+            underflow: self.underflow || other.underflow,
+            inexact: self.inexact || other.inexact,
+        }
+    }
+}
+
+/// Result of an arithmetic operation: the bits of the rounded value and
+/// the exceptions it raised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub bits: u64,
+    pub flags: Flags,
+}
+
+impl Outcome {
+    const fn exact(bits: u64) -> Outcome {
+        Outcome {
+            bits,
+            flags: Flags {
+                invalid: false,
+                division_by_zero: false,
+                overflow: false,
+                underflow: false,
+                inexact: false,
+            },
+        }
+    }
+
+    const fn invalid(format: &Format) -> Outcome {
+        let mut outcome = Outcome::exact(format.quiet_nan());
+        outcome.flags.invalid = true;
+        outcome
+    }
+
+    const fn rounded(rounded: Rounded) -> Outcome {
+        let mut outcome = Outcome::exact(rounded.bits);
+        outcome.flags.overflow = rounded.overflow;
+        outcome.flags.underflow = rounded.underflow;
+        outcome.flags.inexact = rounded.inexact;
+        outcome
+    }
+}
+
+/// Correctly rounded arithmetic on encodings of this format, e.g. to
+/// emulate binary16 or FP8 hardware. Every operation computes the exact
+/// result and rounds it once with [`RoundingMode`], like the parsers do.
+/// FP8 E4M3 hardware has no infinities: use [`Format::E4M3FN`], where
+/// overflows and divisions by zero give its NaN instead.
+///
+/// A NaN operand gives that NaN back quieted (the first one when there are
+/// several) and raises `invalid` if any operand is a signaling NaN. Invalid
+/// operations such as `inf - inf` return the default quiet NaN. Underflow
+/// is raised for tiny results, detected before rounding, that are also
+/// inexact.
+impl Format {
+    pub fn add(&self, a: u64, b: u64, mode: RoundingMode) -> Outcome {
+        if let Some(nan) = self.propagate_nan(&[a, b]) {
+            return nan;
+        }
+        if self.is_infinite(a) && self.is_infinite(b) && self.sign(a) != self.sign(b) {
+            return Outcome::invalid(self);
+        }
+        if self.is_infinite(a) || self.is_infinite(b) {
+            let infinite = if self.is_infinite(a) { a } else { b };
+            return Outcome::exact(infinite & self.bits_mask());
+        }
+        let (sign_a, significand_a, exponent_a) = self.decompose(a);
+        let (sign_b, significand_b, exponent_b) = self.decompose(b);
+        Outcome::rounded(self.sum(
+            (sign_a, significand_a as u128, exponent_a),
+            (sign_b, significand_b as u128, exponent_b),
+            mode,
+        ))
+    }
+
+    pub fn sub(&self, a: u64, b: u64, mode: RoundingMode) -> Outcome {
+        if let Some(nan) = self.propagate_nan(&[a, b]) {
+            return nan;
+        }
+        self.add(a, b ^ self.sign_mask(), mode)
+    }
+
+    pub fn mul(&self, a: u64, b: u64, mode: RoundingMode) -> Outcome {
+        if let Some(nan) = self.propagate_nan(&[a, b]) {
+            return nan;
+        }
+        let sign = self.sign(a) != self.sign(b);
+        if self.is_infinite(a) || self.is_infinite(b) {
+            if self.is_zero(a) || self.is_zero(b) {
+                return Outcome::invalid(self);
+            }
+            return Outcome::exact(self.infinity(sign));
+        }
+        let (_, significand_a, exponent_a) = self.decompose(a);
+        let (_, significand_b, exponent_b) = self.decompose(b);
+        Outcome::rounded(self.round_pack(
+            sign,
+            significand_a as u128 * significand_b as u128,
+            exponent_a + exponent_b,
+            false,
+            mode,
+        ))
+    }
+
+    pub fn div(&self, a: u64, b: u64, mode: RoundingMode) -> Outcome {
+        if let Some(nan) = self.propagate_nan(&[a, b]) {
+            return nan;
+        }
+        let sign = self.sign(a) != self.sign(b);
+        if self.is_infinite(a) {
+            if self.is_infinite(b) {
+                return Outcome::invalid(self);
+            }
+            return Outcome::exact(self.infinity(sign));
+        }
+        if self.is_infinite(b) {
+            return Outcome::exact(self.zero(sign));
+        }
+        if self.is_zero(b) {
+            if self.is_zero(a) {
+                return Outcome::invalid(self);
+            }
+            let mut outcome = Outcome::exact(self.infinity(sign));
+            outcome.flags.division_by_zero = true;
+            return outcome;
+        }
+        if self.is_zero(a) {
+            return Outcome::exact(self.zero(sign));
+        }
+        let (_, significand_a, exponent_a) = self.decompose(a);
+        let (_, significand_b, exponent_b) = self.decompose(b);
+        // Moving the dividend up to bit 125 leaves a quotient of at least 64
+        // bits, more than any precision plus a round bit.
+        let shift = (significand_a as u128).leading_zeros() as i32 - 2;
+        let dividend = (significand_a as u128) << shift;
+        let divisor = significand_b as u128;
+        Outcome::rounded(self.round_pack(
+            sign,
+            dividend / divisor,
+            exponent_a - shift - exponent_b,
+            !dividend.is_multiple_of(divisor),
+            mode,
+        ))
+    }
+
+    pub fn sqrt(&self, a: u64, mode: RoundingMode) -> Outcome {
+        if let Some(nan) = self.propagate_nan(&[a]) {
+            return nan;
+        }
+        if self.is_zero(a) {
+            return Outcome::exact(a & self.bits_mask());
+        }
+        if self.sign(a) {
+            return Outcome::invalid(self);
+        }
+        if self.is_infinite(a) {
+            return Outcome::exact(a & self.bits_mask());
+        }
+        let (_, significand, exponent) = self.decompose(a);
+        // Up to bit 124 or 125, whichever leaves an even exponent, so the
+        // root has at least 63 bits.
+        let mut shift = (significand as u128).leading_zeros() as i32 - 2;
+        if (exponent - shift).rem_euclid(2) == 1 {
+            shift -= 1;
+        }
+        let square = (significand as u128) << shift;
+        let root = square.isqrt();
+        Outcome::rounded(self.round_pack(
+            false,
+            root,
+            (exponent - shift) / 2,
+            root * root != square,
+            mode,
+        ))
+    }
+
+    /// IEEE 754 `remainder`: `a - n * b` where `n` is `a / b` rounded to the
+    /// nearest integer, ties to even. The result is always exact, so there
+    /// is no rounding mode; it lies within `[-|b| / 2, |b| / 2]` and a zero
+    /// result keeps the sign of `a`.
+    pub fn rem(&self, a: u64, b: u64) -> Outcome {
+        if let Some(nan) = self.propagate_nan(&[a, b]) {
+            return nan;
+        }
+        if self.is_infinite(a) || self.is_zero(b) {
+            return Outcome::invalid(self);
+        }
+        if self.is_infinite(b) || self.is_zero(a) {
+            return Outcome::exact(a & self.bits_mask());
+        }
+        let (sign, significand_a, exponent_a) = self.decompose(a);
+        let (_, significand_b, exponent_b) = self.decompose(b);
+        // `remainder` and `divisor` count units of 2^exponent; `odd` is the
+        // lowest bit of the truncated quotient.
+        let (remainder, divisor, exponent, odd) = if exponent_a >= exponent_b {
+            let divisor = significand_b as u128;
+            let mut remainder = significand_a as u128 % divisor;
+            let mut odd = (significand_a as u128 / divisor) & 1 == 1;
+            let mut distance = exponent_a - exponent_b;
+            while distance > 0 {
+                let step = distance.min(64);
+                let dividend = remainder << step;
+                odd = (dividend / divisor) & 1 == 1;
+                remainder = dividend % divisor;
+                distance -= step;
+            }
+            (remainder, divisor, exponent_b, odd)
+        } else if exponent_b - exponent_a > 64 {
+            // b is normal, so |a| < 2^(62 + exponent_a) is below |b| / 4.
+            return Outcome::exact(a & self.bits_mask());
+        } else {
+            let dividend = significand_a as u128;
+            let divisor = (significand_b as u128) << (exponent_b - exponent_a);
+            (
+                dividend % divisor,
+                divisor,
+                exponent_a,
+                (dividend / divisor) & 1 == 1,
+            )
+        };
+        let (remainder, sign) = if 2 * remainder > divisor || (2 * remainder == divisor && odd) {
+            (divisor - remainder, !sign)
+        } else {
+            (remainder, sign)
+        };
+        if remainder == 0 {
+            return Outcome::exact(self.zero(self.sign(a)));
+        }
+        Outcome::rounded(self.round_pack(
+            sign,
+            remainder,
+            exponent,
+            false,
+            RoundingMode::NearestTiesToEven,
+        ))
+    }
+
+    /// Fused multiply-add `a * b + c` with a single rounding. `inf * 0`
+    /// raises `invalid` even when `c` is a quiet NaN.
+    pub fn fma(&self, a: u64, b: u64, c: u64, mode: RoundingMode) -> Outcome {
+        let product_infinite = self.is_infinite(a) || self.is_infinite(b);
+        let product_invalid = product_infinite && (self.is_zero(a) || self.is_zero(b));
+        if let Some(mut nan) = self.propagate_nan(&[a, b, c]) {
+            nan.flags.invalid |= product_invalid;
+            return nan;
+        }
+        if product_invalid {
+            return Outcome::invalid(self);
+        }
+        let sign = self.sign(a) != self.sign(b);
+        if product_infinite {
+            if self.is_infinite(c) && self.sign(c) != sign {
+                return Outcome::invalid(self);
+            }
+            return Outcome::exact(self.infinity(sign));
+        }
+        if self.is_infinite(c) {
+            return Outcome::exact(c & self.bits_mask());
+        }
+        let (_, significand_a, exponent_a) = self.decompose(a);
+        let (_, significand_b, exponent_b) = self.decompose(b);
+        let (sign_c, significand_c, exponent_c) = self.decompose(c);
+        Outcome::rounded(self.sum(
+            (
+                sign,
+                significand_a as u128 * significand_b as u128,
+                exponent_a + exponent_b,
+            ),
+            (sign_c, significand_c as u128, exponent_c),
+            mode,
+        ))
+    }
+
+    fn propagate_nan(&self, operands: &[u64]) -> Option<Outcome> {
+        let nan = operands.iter().find(|bits| self.is_nan(**bits))?;
+        let mut outcome = Outcome::exact(self.quiet(*nan));
+        outcome.flags.invalid = operands.iter().any(|bits| self.is_signaling_nan(*bits));
+        Some(outcome)
+    }
+
+    /// Rounds the sum of two signed magnitudes `significand * 2^exponent`
+    /// whose significands have at most 124 bits.
+    fn sum(&self, a: (bool, u128, i32), b: (bool, u128, i32), mode: RoundingMode) -> Rounded {
+        let exact_zero_sign = |a: (bool, u128, i32), b: (bool, u128, i32)| {
+            if a.0 == b.0 {
+                a.0
+            } else {
+                mode == RoundingMode::TowardNegative
+            }
+        };
+        if a.1 == 0 || b.1 == 0 {
+            let (sign, significand, exponent) = match (a.1, b.1) {
+                (0, 0) => (exact_zero_sign(a, b), 0, 0),
+                (0, _) => b,
+                _ => a,
+            };
+            return self.round_pack(sign, significand, exponent, false, mode);
+        }
+        // Both significands start at bit 123, so the larger exponent belongs
+        // to the larger magnitude.
+        let normalize = |(sign, significand, exponent): (bool, u128, i32)| {
+            let shift = significand.leading_zeros() as i32 - 4;
+            (sign, significand << shift, exponent - shift)
+        };
+        let (a, b) = (normalize(a), normalize(b));
+        let (large, small) = if (a.2, a.1) >= (b.2, b.1) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let distance = large.2 - small.2;
+        // Close exponents are aligned exactly using the headroom above bit
+        // 123. Otherwise the small operand is shifted right and the bits it
+        // loses only matter as a sticky bit: the sum keeps at least 122
+        // significant bits.
+        let (large_significand, small_significand, exponent, sticky) = if distance <= 3 {
+            (large.1 << distance, small.1, small.2, false)
+        } else if distance >= 128 {
+            (large.1, 0, large.2, true)
+        } else {
+            let lost = small.1 & ((1 << distance) - 1) != 0;
+            (large.1, small.1 >> distance, large.2, lost)
+        };
+        let significand = if large.0 == small.0 {
+            large_significand + small_significand
+        } else {
+            // large - (small + s) == (large - small - 1) + (1 - s)
+            large_significand - small_significand - sticky as u128
+        };
+        if significand == 0 {
+            return self.round_pack(exact_zero_sign(a, b), 0, 0, false, mode);
+        }
+        self.round_pack(large.0, significand, exponent, sticky, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754::testing::Xorshift;
+
+    const MODES: [RoundingMode; 5] = [
+        RoundingMode::NearestTiesToEven,
+        RoundingMode::NearestTiesToAway,
+        RoundingMode::TowardZero,
+        RoundingMode::TowardPositive,
+        RoundingMode::TowardNegative,
+    ];
+
+    /// Rounds a finite binary64 value, computed exactly or with at least
+    /// 2p + 2 bits, to `format`.
+    fn round_from_f64(format: Format, value: f64, mode: RoundingMode) -> u64 {
+        let (sign, significand, exponent) = Format::BINARY64.decompose(value.to_bits());
+        format
+            .round_pack(sign, significand as u128, exponent, false, mode)
+            .bits
+    }
+
+    #[test]
+    fn test_against_hardware() {
+        let nearest = RoundingMode::NearestTiesToEven;
+        let mut random = Xorshift::new(0x243F6A8885A308D3);
+        let specials = [
+            0, 0x80000000, 1, 0x7F7FFFFF, 0x00800000, 0x7F800000, 0x3F800000,
+        ];
+        for i in 0..20000 {
+            let (a, b, c) = if i < specials.len() * specials.len() {
+                let a = specials[i % specials.len()];
+                let b = specials[i / specials.len()];
+                (a, b, random.next_u64() as u32)
+            } else {
+                let a = random.next_u64();
+                let b = random.next_u64();
+                // Nearby exponents exercise cancellation.
+                let b = if i % 3 == 0 {
+                    (a & 0xFF800000) ^ (b & 0x807FFFFF)
+                } else {
+                    b
+                };
+                (a as u32, b as u32, random.next_u64() as u32)
+            };
+            let (x, y, z) = (f32::from_bits(a), f32::from_bits(b), f32::from_bits(c));
+            let format = Format::BINARY32;
+            let (a, b, c) = (a as u64, b as u64, c as u64);
+            let check = |outcome: Outcome, expected: f32| {
+                if expected.is_nan() {
+                    assert!(format.is_nan(outcome.bits), "{:08X} {:08X}", a, b);
+                } else {
+                    assert_eq!(
+                        outcome.bits,
+                        expected.to_bits() as u64,
+                        "{:08X} {:08X}",
+                        a,
+                        b
+                    );
+                }
+            };
+            check(format.add(a, b, nearest), x + y);
+            check(format.sub(a, b, nearest), x - y);
+            check(format.mul(a, b, nearest), x * y);
+            check(format.div(a, b, nearest), x / y);
+            check(format.sqrt(a, nearest), x.sqrt());
+            check(format.fma(a, b, c, nearest), x.mul_add(y, z));
+
+            let (a, b, c) = (random.next_u64(), random.next_u64(), random.next_u64());
+            let b = if i % 3 == 0 {
+                (a & 0xFFF0000000000000) ^ (b & 0x800FFFFFFFFFFFFF)
+            } else {
+                b
+            };
+            let (x, y, z) = (f64::from_bits(a), f64::from_bits(b), f64::from_bits(c));
+            let format = Format::BINARY64;
+            let check = |outcome: Outcome, expected: f64| {
+                if expected.is_nan() {
+                    assert!(format.is_nan(outcome.bits), "{:016X} {:016X}", a, b);
+                } else {
+                    assert_eq!(outcome.bits, expected.to_bits(), "{:016X} {:016X}", a, b);
+                }
+            };
+            check(format.add(a, b, nearest), x + y);
+            check(format.sub(a, b, nearest), x - y);
+            check(format.mul(a, b, nearest), x * y);
+            check(format.div(a, b, nearest), x / y);
+            check(format.sqrt(a, nearest), x.sqrt());
+            check(format.fma(a, b, c, nearest), x.mul_add(y, z));
+            // The rounding error of the product, cancelling all leading bits.
+            let product = (-(x * y)).to_bits();
+            check(format.fma(a, b, product, nearest), x.mul_add(y, -(x * y)));
+        }
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        // binary16 sums and products are exact in binary64, quotients and
+        // roots carry enough bits to be rounded once more.
+        let half = Format::BINARY16;
+        let mut random = Xorshift::new(0x13198A2E03707344);
+        for _ in 0..20000 {
+            let (a, b) = (random.next_u64() & 0xFFFF, random.next_u64() & 0xFFFF);
+            if !half.is_finite(a) || !half.is_finite(b) {
+                continue;
+            }
+            let (x, y) = (half.decode_f64(a), half.decode_f64(b));
+            for mode in MODES {
+                let cases = [
+                    (half.add(a, b, mode), x + y),
+                    (half.sub(a, b, mode), x - y),
+                    (half.mul(a, b, mode), x * y),
+                    (half.div(a, b, mode), x / y),
+                    (half.sqrt(a, mode), x.sqrt()),
+                ];
+                for (outcome, exact) in cases {
+                    if !exact.is_finite() || exact == 0.0 {
+                        continue;
+                    }
+                    let expected = round_from_f64(half, exact, mode);
+                    assert_eq!(outcome.bits, expected, "{:04X} {:04X} {:?}", a, b, mode);
+                }
+            }
+        }
+
+        // E4M3: 1 + 2^-4 is 1.0625, halfway between 1 and 1.125.
+        let fp8 = Format::E4M3FN;
+        let one = 0x38;
+        let sixteenth = 0x18;
+        let expected = [0x38, 0x39, 0x38, 0x39, 0x38];
+        for (mode, expected) in MODES.iter().zip(expected) {
+            let outcome = fp8.add(one, sixteenth, *mode);
+            assert_eq!(outcome.bits, expected, "{:?}", mode);
+            assert!(outcome.flags.inexact);
+        }
+        // Above 448 E4M3 has no infinity to round to, only its NaN.
+        let even = RoundingMode::NearestTiesToEven;
+        assert_eq!(fp8.add(0x7E, 0x50, even).bits, 0x7E); // 448 + 16 ties to 448
+        let outcome = fp8.add(0x78, 0x78, even); // 256 + 256
+        assert_eq!(outcome.bits, 0x7F);
+        assert!(outcome.flags.overflow && outcome.flags.inexact);
+        assert_eq!(fp8.mul(0x7E, 0xC0, even).bits, 0xFF); // 448 * -2
+        assert_eq!(fp8.mul(0x7E, 0x40, RoundingMode::TowardZero).bits, 0x7E);
+        assert_eq!(fp8.fma(0x7E, 0x38, 0x7E, even).bits, 0x7F);
+        let outcome = fp8.div(0x38, 0x80, even);
+        assert_eq!(outcome.bits, 0xFF);
+        assert!(outcome.flags.division_by_zero);
+        assert_eq!(fp8.sqrt(0xB8, even).bits, 0x7F);
+        assert_eq!(fp8.sub(0x7F, 0x38, even).bits, 0x7F);
+        assert_eq!(
+            half.add(0x3C00, 0xBC00, RoundingMode::TowardNegative).bits,
+            0x8000
+        );
+        assert_eq!(
+            half.add(0x3C00, 0xBC00, RoundingMode::TowardZero).bits,
+            0x0000
+        );
+        assert_eq!(
+            half.add(0x8000, 0x8000, RoundingMode::NearestTiesToEven)
+                .bits,
+            0x8000
+        );
+    }
+
+    #[test]
+    fn test_flags() {
+        let half = Format::BINARY16;
+        let mode = RoundingMode::NearestTiesToEven;
+        let flags = |invalid, division_by_zero, overflow, underflow, inexact| Flags {
+            invalid,
+            division_by_zero,
+            overflow,
+            underflow,
+            inexact,
+        };
+        let none = Flags::default();
+
+        assert_eq!(
+            half.add(0x3C00, 0x3C00, mode),
+            Outcome {
+                bits: 0x4000,
+                flags: none
+            }
+        );
+        assert_eq!(
+            half.add(0x7C00, 0xFC00, mode),
+            Outcome {
+                bits: 0x7E00,
+                flags: flags(true, false, false, false, false)
+            }
+        );
+        assert_eq!(
+            half.mul(0x7BFF, 0x4000, mode),
+            Outcome {
+                bits: 0x7C00,
+                flags: flags(false, false, true, false, true)
+            }
+        );
+        assert_eq!(
+            half.mul(0x7BFF, 0x4000, RoundingMode::TowardZero).bits,
+            0x7BFF
+        );
+        assert_eq!(
+            half.mul(0x0001, 0x3800, mode),
+            Outcome {
+                bits: 0x0000,
+                flags: flags(false, false, false, true, true)
+            }
+        );
+        assert_eq!(
+            half.div(0x3C00, 0x8000, mode),
+            Outcome {
+                bits: 0xFC00,
+                flags: flags(false, true, false, false, false)
+            }
+        );
+        assert_eq!(
+            half.div(0x0000, 0x0000, mode).flags,
+            flags(true, false, false, false, false)
+        );
+        assert_eq!(
+            half.div(0x3C00, 0x4200, mode).flags,
+            flags(false, false, false, false, true)
+        );
+        assert_eq!(
+            half.sqrt(0x8000, mode),
+            Outcome {
+                bits: 0x8000,
+                flags: none
+            }
+        );
+        assert_eq!(
+            half.sqrt(0xBC00, mode).flags,
+            flags(true, false, false, false, false)
+        );
+        assert_eq!(
+            half.sqrt(0x4400, mode),
+            Outcome {
+                bits: 0x4000,
+                flags: none
+            }
+        );
+
+        // Signaling NaNs are quieted and raise invalid, quiet ones pass.
+        assert_eq!(
+            half.add(0x3C00, 0x7D01, mode),
+            Outcome {
+                bits: 0x7F01,
+                flags: flags(true, false, false, false, false)
+            }
+        );
+        assert_eq!(half.mul(0xFE02, 0x7D01, mode).bits, 0xFE02);
+        assert_eq!(
+            half.sub(0x7E03, 0x3C00, mode),
+            Outcome {
+                bits: 0x7E03,
+                flags: none
+            }
+        );
+        assert_eq!(
+            half.fma(0x7C00, 0x0000, 0x7E00, mode),
+            Outcome {
+                bits: 0x7E00,
+                flags: flags(true, false, false, false, false)
+            }
+        );
+        assert_eq!(
+            half.fma(0x7C00, 0x3C00, 0xFC00, mode).flags,
+            flags(true, false, false, false, false)
+        );
+        assert_eq!(
+            half.fma(0x3C00, 0x3C00, 0xBC00, mode),
+            Outcome {
+                bits: 0x0000,
+                flags: none
+            }
+        );
+        assert!(
+            flags(false, false, true, false, true)
+                .union(flags(true, false, false, false, false))
+                .invalid
+        );
+        assert!(!none.any());
+    }
+
+    #[test]
+    fn test_rem() {
+        let half = Format::BINARY16;
+        let value = |bits| half.decode_f64(bits);
+        // 5 rem 3 = -1, 7 rem 2 = -1 (ties to even quotient 4), 5 rem 2 = 1
+        assert_eq!(half.rem(0x4500, 0x4200).bits, 0xBC00);
+        assert_eq!(half.rem(0x4700, 0x4000).bits, 0xBC00);
+        assert_eq!(half.rem(0x4500, 0x4000).bits, 0x3C00);
+        assert_eq!(half.rem(0xC400, 0x4000).bits, 0x8000);
+        assert_eq!(half.rem(0x3C00, 0x7C00).bits, 0x3C00);
+        assert!(half.rem(0x7C00, 0x3C00).flags.invalid);
+        assert!(half.rem(0x3C00, 0x0000).flags.invalid);
+
+        let mut random = Xorshift::new(0xA4093822299F31D0);
+        for _ in 0..20000 {
+            let (a, b) = (random.next_u64() & 0xFFFF, random.next_u64() & 0xFFFF);
+            if !half.is_finite(a) || !half.is_finite(b) || half.is_zero(b) {
+                continue;
+            }
+            let outcome = half.rem(a, b);
+            assert!(!outcome.flags.any());
+            // a - r is an exact multiple n of b with n even on ties.
+            let (x, y, r) = (value(a), value(b), value(outcome.bits));
+            let n = (x - r) / y;
+            assert_eq!(n, n.round(), "{:04X} {:04X}", a, b);
+            assert!(r.abs() <= y.abs() / 2.0);
+            if r.abs() == y.abs() / 2.0 {
+                assert_eq!(n % 2.0, 0.0);
+            }
+        }
+    }
+}
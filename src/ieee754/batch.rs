@@ -0,0 +1,330 @@
+use crate::error::Error;
+use crate::ieee754::format::Format;
+use crate::ieee754::ieee754_32bit::IEEE754_32bit;
+use crate::ieee754::ieee754_64bit::IEEE754_64bit;
+use crate::ieee754::options::{Endianness, FormatOptions};
+use alloc::{string::String, vec, vec::Vec};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementError {
+    pub index: usize,
+    pub error: Error,
+}
+
+/// Outcome of decoding into a caller provided slice. Elements listed in
+/// `errors` leave their slot in the output untouched.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BatchReport {
+    pub decoded: usize,
+    pub errors: Vec<ElementError>,
+}
+
+#[derive(Debug)]
+pub struct Batch;
+
+impl Batch {
+    /// Splits `bytes` into consecutive encodings of `format` and returns the
+    /// raw bit pattern of each. A trailing partial element is reported as an
+    /// error instead of being dropped silently.
+    pub fn decode_bits(
+        bytes: &[u8],
+        format: Format,
+        endianness: Endianness,
+    ) -> Vec<Result<u64, Error>> {
+        let width = Self::width(format);
+        bytes
+            .chunks(width)
+            .map(|chunk| Self::decode_element(chunk, format, endianness))
+            .collect()
+    }
+
+    pub fn decode_32bit(bytes: &[u8], endianness: Endianness) -> Vec<Result<f32, Error>> {
+        bytes
+            .chunks(4)
+            .map(|chunk| {
+                let bits = Self::decode_element(chunk, Format::BINARY32, endianness)?;
+                IEEE754_32bit::decode(bits as u32)
+            })
+            .collect()
+    }
+
+    pub fn decode_64bit(bytes: &[u8], endianness: Endianness) -> Vec<Result<f64, Error>> {
+        bytes
+            .chunks(8)
+            .map(|chunk| {
+                let bits = Self::decode_element(chunk, Format::BINARY64, endianness)?;
+                IEEE754_64bit::decode(bits)
+            })
+            .collect()
+    }
+
+    pub fn decode_32bit_into(
+        bytes: &[u8],
+        endianness: Endianness,
+        output: &mut [f32],
+    ) -> BatchReport {
+        Self::decode_into(bytes, Format::BINARY32, endianness, output, |bits| {
+            IEEE754_32bit::decode(bits as u32)
+        })
+    }
+
+    pub fn decode_64bit_into(
+        bytes: &[u8],
+        endianness: Endianness,
+        output: &mut [f64],
+    ) -> BatchReport {
+        Self::decode_into(bytes, Format::BINARY64, endianness, output, |bits| {
+            IEEE754_64bit::decode(bits)
+        })
+    }
+
+    pub fn encode_32bit(values: &[f32], endianness: Endianness) -> Vec<u8> {
+        let mut bytes = vec![0; values.len() * 4];
+        for (value, output) in values.iter().zip(bytes.chunks_mut(4)) {
+            endianness.write(IEEE754_32bit::encode(*value) as u64, output);
+        }
+        bytes
+    }
+
+    pub fn encode_64bit(values: &[f64], endianness: Endianness) -> Vec<u8> {
+        let mut bytes = vec![0; values.len() * 8];
+        for (value, output) in values.iter().zip(bytes.chunks_mut(8)) {
+            endianness.write(IEEE754_64bit::encode(*value), output);
+        }
+        bytes
+    }
+
+    pub fn encode_32bit_hex(values: &[f32], options: &FormatOptions) -> Vec<String> {
+        values
+            .iter()
+            .map(|value| options.format_hex(Format::BINARY32, IEEE754_32bit::encode(*value) as u64))
+            .collect()
+    }
+
+    pub fn encode_64bit_hex(values: &[f64], options: &FormatOptions) -> Vec<String> {
+        values
+            .iter()
+            .map(|value| options.format_hex(Format::BINARY64, IEEE754_64bit::encode(*value)))
+            .collect()
+    }
+
+    /// Sorts the elements of `bytes` in place by IEEE 754 `totalOrder`, so
+    /// NaNs and signed zeros get a fixed position. Nothing is written when
+    /// an element is malformed.
+    pub fn sort_total_order(
+        bytes: &mut [u8],
+        format: Format,
+        endianness: Endianness,
+    ) -> Result<(), Error> {
+        let values = Self::sorted_bits(bytes, format, endianness)?;
+        for (bits, output) in values.iter().zip(bytes.chunks_mut(Self::width(format))) {
+            endianness.write(*bits, output);
+        }
+        Ok(())
+    }
+
+    /// Sorted copy of `bytes` (see [`Batch::sort_total_order`]) keeping one
+    /// element per distinct bit pattern. `-0` and `+0` stay apart, as do
+    /// NaNs with different payloads.
+    pub fn dedup_total_order(
+        bytes: &[u8],
+        format: Format,
+        endianness: Endianness,
+    ) -> Result<Vec<u8>, Error> {
+        let mut values = Self::sorted_bits(bytes, format, endianness)?;
+        values.dedup();
+        let width = Self::width(format);
+        let mut output = vec![0; values.len() * width];
+        for (bits, chunk) in values.iter().zip(output.chunks_mut(width)) {
+            endianness.write(*bits, chunk);
+        }
+        Ok(output)
+    }
+
+    fn sorted_bits(
+        bytes: &[u8],
+        format: Format,
+        endianness: Endianness,
+    ) -> Result<Vec<u64>, Error> {
+        let mut values = Self::decode_bits(bytes, format, endianness)
+            .into_iter()
+            .collect::<Result<Vec<u64>, Error>>()?;
+        values.sort_unstable_by_key(|bits| format.sort_key(*bits));
+        Ok(values)
+    }
+
+    /// Number of bytes holding one element of `format`.
+    pub fn width(format: Format) -> usize {
+        format.total_bits().div_ceil(8) as usize
+    }
+
+    /// Reads a single element that has to span exactly the bytes of `chunk`.
+    pub fn decode_element(
+        chunk: &[u8],
+        format: Format,
+        endianness: Endianness,
+    ) -> Result<u64, Error> {
+        let width = Self::width(format);
+        if chunk.len() != width {
+            return Err(Error::InvalidBitLength {
+                expected: width * 8,
+                actual: chunk.len() * 8,
+            });
+        }
+        let bits = endianness.read(chunk);
+        if bits & !format.bits_mask() != 0 {
+            return Err(Error::InvalidBitLength {
+                expected: format.total_bits() as usize,
+                actual: width * 8,
+            });
+        }
+        Ok(bits)
+    }
+
+    fn decode_into<T, F>(
+        bytes: &[u8],
+        format: Format,
+        endianness: Endianness,
+        output: &mut [T],
+        decode: F,
+    ) -> BatchReport
+    where
+        F: Fn(u64) -> Result<T, Error>,
+    {
+        let mut report = BatchReport::default();
+        let width = Self::width(format);
+        let count = bytes.len().div_ceil(width);
+        for (index, chunk) in bytes.chunks(width).enumerate() {
+            let Some(slot) = output.get_mut(index) else {
+                report.errors.push(ElementError {
+                    index,
+                    error: Error::BufferTooSmall {
+                        needed: count,
+                        available: index,
+                    },
+                });
+                break;
+            };
+            match Self::decode_element(chunk, format, endianness).and_then(&decode) {
+                Ok(value) => {
+                    *slot = value;
+                    report.decoded += 1;
+                }
+                Err(error) => report.errors.push(ElementError { index, error }),
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_decode() {
+        let bytes = [
+            0xc0, 0x2d, 0xf8, 0x54, // -2.7182817
+            0x7f, 0xc0, 0x00, 0x00, // NaN
+            0x3f, 0x00, 0x00, 0x00, // 0.5
+            0x3f, 0x80, // partial
+        ];
+        let output = Batch::decode_32bit(&bytes, Endianness::Big);
+        assert_eq!(output.len(), 4);
+        assert_eq!(output[0], Ok(-2.7182817));
+        assert_eq!(output[1], Err(Error::ExponentAll1s));
+        assert_eq!(output[2], Ok(0.5));
+        assert_eq!(
+            output[3],
+            Err(Error::InvalidBitLength {
+                expected: 32,
+                actual: 16
+            })
+        );
+
+        let mut values = [0.0f32; 3];
+        let report = Batch::decode_32bit_into(&bytes[..12], Endianness::Big, &mut values);
+        assert_eq!(report.decoded, 2);
+        assert_eq!(
+            report.errors,
+            vec![ElementError {
+                index: 1,
+                error: Error::ExponentAll1s
+            }]
+        );
+        assert_eq!(values, [-2.7182817, 0.0, 0.5]);
+
+        let mut values = [0.0f64; 1];
+        let bytes = Batch::encode_64bit(&[1.5, -3.125], Endianness::Little);
+        let report = Batch::decode_64bit_into(&bytes, Endianness::Little, &mut values);
+        assert_eq!(report.decoded, 1);
+        assert_eq!(
+            report.errors[0].error,
+            Error::BufferTooSmall {
+                needed: 2,
+                available: 1
+            }
+        );
+        assert_eq!(
+            Batch::decode_bits(&[0x3c, 0x00, 0x00, 0xc0], Format::BINARY16, Endianness::Big),
+            vec![Ok(0x3C00), Ok(0x00C0)]
+        );
+    }
+
+    #[test]
+    fn test_batch_encode() {
+        let values = [-2.7182817f32, 0.5];
+        let bytes = Batch::encode_32bit(&values, Endianness::Big);
+        assert_eq!(bytes, [0xc0, 0x2d, 0xf8, 0x54, 0x3f, 0x00, 0x00, 0x00]);
+        let bytes = Batch::encode_32bit(&values, Endianness::Little);
+        assert_eq!(bytes, [0x54, 0xf8, 0x2d, 0xc0, 0x00, 0x00, 0x00, 0x3f]);
+        let decoded: Vec<f32> = Batch::decode_32bit(&bytes, Endianness::Little)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(decoded, values);
+
+        let hex = Batch::encode_64bit_hex(&[-85.125, 0.0], &FormatOptions::new().prefix(true));
+        assert_eq!(hex, vec!["0xC055480000000000", "0x0000000000000000"]);
+        assert_eq!(
+            Batch::encode_32bit_hex(&[-33.333332], &FormatOptions::new()),
+            vec!["C2055555"]
+        );
+    }
+
+    #[test]
+    fn test_batch_total_order() {
+        let mut bytes = vec![
+            0x00, 0x7E, // NaN
+            0x00, 0x3C, // 1
+            0x00, 0x00, // +0
+            0x00, 0xFC, // -inf
+            0x00, 0x80, // -0
+            0x00, 0x3C, // 1
+            0x00, 0x7E, // NaN
+        ];
+        assert_eq!(
+            Batch::dedup_total_order(&bytes, Format::BINARY16, Endianness::Little),
+            Ok(vec![
+                0x00, 0xFC, 0x00, 0x80, 0x00, 0x00, 0x00, 0x3C, 0x00, 0x7E
+            ])
+        );
+        Batch::sort_total_order(&mut bytes, Format::BINARY16, Endianness::Little).unwrap();
+        assert_eq!(
+            Batch::decode_bits(&bytes, Format::BINARY16, Endianness::Little),
+            vec![
+                Ok(0xFC00),
+                Ok(0x8000),
+                Ok(0x0000),
+                Ok(0x3C00),
+                Ok(0x3C00),
+                Ok(0x7E00),
+                Ok(0x7E00)
+            ]
+        );
+
+        let mut partial = vec![0x3C, 0x00, 0x00];
+        assert!(Batch::sort_total_order(&mut partial, Format::BINARY16, Endianness::Big).is_err());
+        assert_eq!(partial, vec![0x3C, 0x00, 0x00]);
+    }
+}
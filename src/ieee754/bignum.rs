@@ -0,0 +1,254 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+
+/// Minimal arbitrary precision unsigned integer used where exact arithmetic is
+/// required (decimal conversions). Limbs are stored little endian.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn from_u64(value: u64) -> BigUint {
+        let mut number = BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+        number.normalize();
+        number
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn bit_len(&self) -> u32 {
+        match self.limbs.last() {
+            Some(last) => 32 * (self.limbs.len() as u32 - 1) + (32 - last.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    pub fn bit(&self, index: u32) -> bool {
+        match self.limbs.get((index / 32) as usize) {
+            Some(limb) => (limb >> (index % 32)) & 1 == 1,
+            None => false,
+        }
+    }
+
+    /// True if any of the lowest `count` bits is set.
+    pub fn any_low_bits(&self, count: u32) -> bool {
+        let whole = (count / 32) as usize;
+        let partial = count % 32;
+        for (i, limb) in self.limbs.iter().enumerate() {
+            if i < whole {
+                if *limb != 0 {
+                    return true;
+                }
+            } else {
+                return i == whole && partial > 0 && limb & ((1 << partial) - 1) != 0;
+            }
+        }
+        false
+    }
+
+    /// Value of the number, which has to fit in 128 bits.
+    pub fn to_u128(&self) -> u128 {
+        self.limbs
+            .iter()
+            .take(4)
+            .enumerate()
+            .fold(0, |acc, (i, limb)| acc | (*limb as u128) << (32 * i))
+    }
+
+    pub fn mul_small(&mut self, factor: u32) {
+        let mut carry: u64 = 0;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    pub fn add_small(&mut self, value: u32) {
+        let mut carry = value as u64;
+        for limb in self.limbs.iter_mut() {
+            if carry == 0 {
+                break;
+            }
+            let sum = *limb as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    pub fn add(&mut self, other: &BigUint) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+        let mut carry: u64 = 0;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb as u64 + *other.limbs.get(i).unwrap_or(&0) as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    /// Subtracts `other`, which must not be greater than `self`.
+    pub fn sub(&mut self, other: &BigUint) {
+        let mut borrow: i64 = 0;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let mut difference = *limb as i64 - *other.limbs.get(i).unwrap_or(&0) as i64 - borrow;
+            borrow = 0;
+            if difference < 0 {
+                difference += 1 << 32;
+                borrow = 1;
+            }
+            *limb = difference as u32;
+        }
+        self.normalize();
+    }
+
+    pub fn mul_pow(&mut self, base: u32, exponent: u32) {
+        for _ in 0..exponent {
+            self.mul_small(base);
+        }
+    }
+
+    pub fn pow(base: u32, exponent: u32) -> BigUint {
+        let mut number = BigUint::from_u64(1);
+        number.mul_pow(base, exponent);
+        number
+    }
+
+    pub fn shl(&mut self, bits: u32) {
+        if self.is_zero() || bits == 0 {
+            return;
+        }
+        let whole = (bits / 32) as usize;
+        let partial = bits % 32;
+        if partial > 0 {
+            let mut carry = 0;
+            for limb in self.limbs.iter_mut() {
+                let shifted = (*limb << partial) | carry;
+                carry = *limb >> (32 - partial);
+                *limb = shifted;
+            }
+            if carry > 0 {
+                self.limbs.push(carry);
+            }
+        }
+        self.limbs.splice(0..0, core::iter::repeat_n(0, whole));
+    }
+
+    pub fn shr(&mut self, bits: u32) {
+        let whole = (bits / 32) as usize;
+        if whole >= self.limbs.len() {
+            self.limbs.clear();
+            return;
+        }
+        self.limbs.drain(0..whole);
+        let partial = bits % 32;
+        if partial > 0 {
+            for i in 0..self.limbs.len() {
+                let high = self
+                    .limbs
+                    .get(i + 1)
+                    .map_or(0, |next| next << (32 - partial));
+                self.limbs[i] = (self.limbs[i] >> partial) | high;
+            }
+        }
+        self.normalize();
+    }
+
+    /// Divides in place by a small divisor and returns the remainder.
+    pub fn div_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder: u64 = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 32) | *limb as u64;
+            *limb = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        self.normalize();
+        remainder as u32
+    }
+
+    pub fn to_decimal_string(&self) -> String {
+        if self.is_zero() {
+            return "0".to_string();
+        }
+        let mut number = self.clone();
+        let mut chunks: Vec<u32> = Vec::new();
+        while !number.is_zero() {
+            chunks.push(number.div_small(1_000_000_000));
+        }
+        let mut output = String::new();
+        for (i, chunk) in chunks.iter().rev().enumerate() {
+            if i == 0 {
+                output.push_str(&chunk.to_string());
+            } else {
+                output.push_str(&format!("{:09}", chunk));
+            }
+        }
+        output
+    }
+
+    /// Long division returning `(quotient, remainder)`.
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        let mut quotient = BigUint::zero();
+        let mut remainder = BigUint::zero();
+        let length = self.bit_len();
+        quotient.limbs.resize(length.div_ceil(32) as usize, 0);
+        for index in (0..length).rev() {
+            remainder.shl(1);
+            if self.bit(index) {
+                remainder.add_small(1);
+            }
+            if remainder >= *divisor {
+                remainder.sub(divisor);
+                quotient.limbs[(index / 32) as usize] |= 1 << (index % 32);
+            }
+        }
+        quotient.normalize();
+        (quotient, remainder)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::Error;
+use crate::ieee754::format::Format;
+use crate::ieee754::hex::parse_digits;
+use crate::ieee754::options::Endianness;
+
+#[derive(Debug)]
+pub struct BinaryParser;
+
+impl BinaryParser {
+    /// Reads the bit pattern of `format` from a string of `0`/`1` digits such
+    /// as `1 10000100 00001010101010101010101` or `0b1100_0010...`. The same
+    /// whitespace, separators and prefixes as `HexParser::parse` are skipped,
+    /// so field separated output of `FormatOptions::format_binary` reads back.
+    pub fn parse(input: &str, format: Format, endianness: Endianness) -> Result<u64, Error> {
+        parse_digits(input, format, endianness, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754::options::{FormatOptions, Grouping};
+
+    #[test]
+    fn test_parse_binary() {
+        let format = Format::BINARY32;
+        for input in [
+            "11000010000001010101010101010101",
+            "1 10000100 00001010101010101010101",
+            "1|10000100|00001010101010101010101",
+            "0b11000010_00000101_01010101_01010101",
+        ] {
+            assert_eq!(
+                BinaryParser::parse(input, format, Endianness::Big).unwrap(),
+                0xC2055555
+            );
+        }
+        assert_eq!(
+            BinaryParser::parse("00000000 00111100", Format::BINARY16, Endianness::Little).unwrap(),
+            0x3C00
+        );
+        assert!(matches!(
+            BinaryParser::parse("1 10000102 0", Format::new(8, 1).unwrap(), Endianness::Big),
+            Err(Error::UnexpectedCharacter {
+                position: 9,
+                character: '2'
+            })
+        ));
+        assert!(matches!(
+            BinaryParser::parse("1 1000010 0", Format::new(8, 1).unwrap(), Endianness::Big),
+            Err(Error::InvalidDigitCount {
+                expected: 10,
+                actual: 9
+            })
+        ));
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let options = FormatOptions::new().grouping(Grouping::Fields);
+        for format in [
+            Format::BINARY16,
+            Format::BFLOAT16,
+            Format::new(4, 3).unwrap(),
+        ] {
+            for bits in 0..(1u64 << format.total_bits()) {
+                let text = options.format_binary(format, bits);
+                assert_eq!(
+                    BinaryParser::parse(&text, format, Endianness::Big).unwrap(),
+                    bits
+                );
+            }
+        }
+    }
+}
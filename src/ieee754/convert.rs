@@ -0,0 +1,343 @@
+use crate::ieee754::arithmetic::{Flags, Outcome};
+use crate::ieee754::format::Format;
+use crate::ieee754::rounding::RoundingMode;
+
+impl Format {
+    /// Rounds `value` to the nearest encoding of this format (ties to
+    /// even), usable in constants such as
+    /// `const ONE_HALF: u64 = Format::BINARY16.encode_f32(0.5);`.
+    pub const fn encode_f32(&self, value: f32) -> u64 {
+        self.convert(value.to_bits() as u64, Format::BINARY32)
+    }
+
+    pub const fn encode_f64(&self, value: f64) -> u64 {
+        self.convert(value.to_bits(), Format::BINARY64)
+    }
+
+    /// Widens an encoding of this format to `f32`, rounding to nearest when
+    /// the format is wider than binary32.
+    pub const fn decode_f32(&self, bits: u64) -> f32 {
+        f32::from_bits(Format::BINARY32.convert(bits, *self) as u32)
+    }
+
+    pub const fn decode_f64(&self, bits: u64) -> f64 {
+        f64::from_bits(Format::BINARY64.convert(bits, *self))
+    }
+
+    /// Re-encodes `bits` of `source` in this format with the default
+    /// [`ConvertOptions`].
+    const fn convert(&self, bits: u64, source: Format) -> u64 {
+        self.convert_from(bits, source, &ConvertOptions::new()).bits
+    }
+
+    /// Re-encodes `bits` of `source` in this format, e.g. binary64 to
+    /// binary16 or binary32 to FP8 ([`Format::E4M3FN`]), with the rounding, overflow, subnormal
+    /// and NaN handling chosen in `options`. Widening conversions are exact.
+    /// Flags report rounding (`inexact`), overflow, underflow and signaling
+    /// NaN inputs (`invalid`).
+    pub const fn convert_from(
+        &self,
+        bits: u64,
+        source: Format,
+        options: &ConvertOptions,
+    ) -> Outcome {
+        let sign = source.sign(bits);
+        let mut outcome = Outcome {
+            bits: 0,
+            flags: Flags {
+                invalid: false,
+                division_by_zero: false,
+                overflow: false,
+                underflow: false,
+                inexact: false,
+            },
+        };
+        if source.is_nan(bits) {
+            outcome.flags.invalid = source.is_signaling_nan(bits);
+            outcome.bits = match options.nan {
+                NanConversion::Canonical => self.quiet_nan(),
+                NanConversion::Propagate => {
+                    let payload = source.mantissa(bits);
+                    let payload = if self.mantissa_bits() >= source.mantissa_bits() {
+                        payload << (self.mantissa_bits() - source.mantissa_bits())
+                    } else {
+                        payload >> (source.mantissa_bits() - self.mantissa_bits())
+                    };
+                    let sign_bit = if sign { self.sign_mask() } else { 0 };
+                    sign_bit | self.quiet_nan() | payload
+                }
+            };
+            return outcome;
+        }
+        if source.is_infinite(bits) {
+            outcome.bits =
+                if !self.has_infinities() && matches!(options.overflow, Overflow::Saturate) {
+                    self.max_finite(sign)
+                } else {
+                    self.infinity(sign)
+                };
+            return outcome;
+        }
+        let (sign, significand, exponent) = source.decompose(bits);
+        let rounded = self.round_pack(sign, significand as u128, exponent, false, options.rounding);
+        outcome.bits = rounded.bits;
+        outcome.flags.inexact = rounded.inexact;
+        outcome.flags.overflow = rounded.overflow;
+        outcome.flags.underflow = rounded.underflow;
+        if rounded.overflow && matches!(options.overflow, Overflow::Saturate) {
+            outcome.bits = self.max_finite(sign);
+        }
+        if self.is_subnormal(rounded.bits) && matches!(options.subnormals, Subnormals::FlushToZero)
+        {
+            outcome.bits = self.zero(sign);
+            outcome.flags.inexact = true;
+            outcome.flags.underflow = true;
+        }
+        outcome
+    }
+}
+
+/// Result of a conversion that overflows the target format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// Infinity, or the largest finite value when the rounding mode rounds
+    /// toward zero, as IEEE 754 requires.
+    #[default]
+    Infinity,
+    /// Always the largest finite value of the same sign, like the saturating
+    /// conversions of FP8 hardware. Infinite inputs stay infinite, unless
+    /// the target format has no infinities (see [`Format::new_finite`]).
+    Saturate,
+}
+
+/// Treatment of results that round to a subnormal of the target format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Subnormals {
+    #[default]
+    Preserve,
+    /// Replaces them with a zero of the same sign.
+    FlushToZero,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NanConversion {
+    /// Keeps the sign and the leading payload bits, padding with zeros when
+    /// widening. The result is always quiet.
+    #[default]
+    Propagate,
+    /// The default quiet NaN of the target format.
+    Canonical,
+}
+
+/// Options for [`Format::convert_from`]. The defaults round to nearest,
+/// ties to even, overflow to infinity, keep subnormals and propagate NaN
+/// payloads, like a hardware conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ConvertOptions {
+    rounding: RoundingMode,
+    overflow: Overflow,
+    subnormals: Subnormals,
+    nan: NanConversion,
+}
+
+impl ConvertOptions {
+    pub const fn new() -> ConvertOptions {
+        ConvertOptions {
+            rounding: RoundingMode::NearestTiesToEven,
+            overflow: Overflow::Infinity,
+            subnormals: Subnormals::Preserve,
+            nan: NanConversion::Propagate,
+        }
+    }
+
+    pub const fn rounding(mut self, rounding: RoundingMode) -> ConvertOptions {
+        self.rounding = rounding;
+        self
+    }
+
+    pub const fn overflow(mut self, overflow: Overflow) -> ConvertOptions {
+        self.overflow = overflow;
+        self
+    }
+
+    pub const fn subnormals(mut self, subnormals: Subnormals) -> ConvertOptions {
+        self.subnormals = subnormals;
+        self
+    }
+
+    pub const fn nan(mut self, nan: NanConversion) -> ConvertOptions {
+        self.nan = nan;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: u64 = Format::BINARY16.encode_f32(1.0);
+    const MAX: u64 = Format::BINARY16.encode_f64(65504.0);
+    const PI: u64 = Format::BFLOAT16.encode_f32(core::f32::consts::PI);
+    const TABLE: [f32; 3] = [
+        Format::BINARY16.decode_f32(0x3555),
+        Format::BINARY16.decode_f32(0x0001),
+        Format::BFLOAT16.decode_f32(0xC049),
+    ];
+
+    #[test]
+    fn test_const_conversion() {
+        assert_eq!(ONE, 0x3C00);
+        assert_eq!(MAX, 0x7BFF);
+        assert_eq!(PI, 0x4049);
+        assert_eq!(TABLE, [0.33325195, 5.9604645e-8, -3.140625]);
+
+        let format = Format::BINARY16;
+        assert_eq!(format.encode_f64(65520.0), 0x7C00);
+        assert_eq!(format.encode_f64(-1e-8), 0x8000);
+        assert_eq!(format.encode_f32(f32::NEG_INFINITY), 0xFC00);
+        assert_eq!(format.encode_f32(f32::from_bits(0xFFC0_2000)), 0xFE01);
+        assert!(format.decode_f64(0x7E01).is_nan());
+        assert_eq!(Format::BINARY32.encode_f64(0.1), 0.1f32.to_bits() as u64);
+        for bits in 0..=0xFFFF {
+            let value = format.decode_f64(bits);
+            if format.is_nan(bits) {
+                assert!(value.is_nan());
+                continue;
+            }
+            assert_eq!(format.encode_f64(value), bits);
+            assert_eq!(format.decode_f32(bits) as f64, value);
+        }
+    }
+    #[test]
+    fn test_convert_from() {
+        let fp8 = Format::new(4, 3).unwrap();
+        let half = Format::BINARY16;
+        let options = ConvertOptions::new();
+
+        // binary64 -> binary16 in every rounding mode: 0.1 lies between
+        // 0x2E66 and 0x2E67, closer to the first.
+        let tenth = 0.1f64.to_bits();
+        let modes = [
+            (RoundingMode::NearestTiesToEven, 0x2E66),
+            (RoundingMode::NearestTiesToAway, 0x2E66),
+            (RoundingMode::TowardZero, 0x2E66),
+            (RoundingMode::TowardPositive, 0x2E67),
+            (RoundingMode::TowardNegative, 0x2E66),
+        ];
+        for (mode, expected) in modes {
+            let outcome = half.convert_from(tenth, Format::BINARY64, &options.rounding(mode));
+            assert_eq!(outcome.bits, expected, "{:?}", mode);
+            assert!(outcome.flags.inexact);
+        }
+
+        // Widening is exact: every bfloat16 is the upper half of a binary32.
+        for bits in 0..=0xFFFF {
+            let outcome = Format::BINARY32.convert_from(bits, Format::BFLOAT16, &options);
+            if Format::BFLOAT16.is_nan(bits) {
+                assert_eq!(outcome.bits, Format::BINARY32.quiet(bits << 16));
+                continue;
+            }
+            assert_eq!(outcome.bits, bits << 16);
+            assert!(!outcome.flags.any());
+        }
+
+        // 480 overflows the IEEE style e4m3, whose largest finite value is 240.
+        let large = 480.0f32.to_bits() as u64;
+        let outcome = fp8.convert_from(large, Format::BINARY32, &options);
+        assert_eq!(outcome.bits, 0x78);
+        assert!(outcome.flags.overflow && outcome.flags.inexact);
+        let saturate = options.overflow(Overflow::Saturate);
+        assert_eq!(
+            fp8.convert_from(large, Format::BINARY32, &saturate).bits,
+            0x77
+        );
+        let negative = (-480.0f32).to_bits() as u64;
+        assert_eq!(
+            fp8.convert_from(negative, Format::BINARY32, &saturate).bits,
+            0xF7
+        );
+        let infinity = f32::INFINITY.to_bits() as u64;
+        assert_eq!(
+            fp8.convert_from(infinity, Format::BINARY32, &saturate).bits,
+            0x78
+        );
+
+        // 2^-8 is a subnormal of e4m3 unless flushed.
+        let tiny = (-0.00390625f32).to_bits() as u64;
+        let outcome = fp8.convert_from(tiny, Format::BINARY32, &options);
+        assert_eq!(outcome.bits, 0x82);
+        assert!(!outcome.flags.any());
+        let flush = options.subnormals(Subnormals::FlushToZero);
+        let outcome = fp8.convert_from(tiny, Format::BINARY32, &flush);
+        assert_eq!(outcome.bits, 0x80);
+        assert!(outcome.flags.underflow && outcome.flags.inexact);
+
+        // NaN payloads keep their leading bits unless canonicalized.
+        let signaling = 0xFFA0_0000;
+        let outcome = half.convert_from(signaling, Format::BINARY32, &options);
+        assert_eq!(outcome.bits, 0xFF00);
+        assert!(outcome.flags.invalid);
+        let canonical = options.nan(NanConversion::Canonical);
+        assert_eq!(
+            half.convert_from(signaling, Format::BINARY32, &canonical)
+                .bits,
+            0x7E00
+        );
+        assert_eq!(
+            Format::BINARY64.convert_from(0x7E01, half, &options).bits,
+            0x7FF8_0400_0000_0000
+        );
+    }
+
+    #[test]
+    fn test_convert_e4m3fn() {
+        // Every code of the OCP FP8 E4M3 table: S.EEEE.MMM with bias 7, no
+        // infinities and S.1111.111 as the only NaN.
+        let fp8 = Format::E4M3FN;
+        for bits in 0..=0xFFu64 {
+            let value = fp8.decode_f32(bits);
+            if bits & 0x7F == 0x7F {
+                assert!(value.is_nan());
+                continue;
+            }
+            let (exponent, mantissa) = ((bits >> 3) & 0xF, (bits & 7) as f32);
+            let magnitude = if exponent == 0 {
+                mantissa * 2f32.powi(-9)
+            } else {
+                (8.0 + mantissa) * 2f32.powi(exponent as i32 - 10)
+            };
+            let expected = if bits & 0x80 != 0 {
+                -magnitude
+            } else {
+                magnitude
+            };
+            assert_eq!(value.to_bits(), expected.to_bits(), "{:#04x}", bits);
+            assert_eq!(fp8.encode_f32(value), bits);
+        }
+        assert_eq!(fp8.decode_f32(0x7E), 448.0);
+        assert_eq!(fp8.decode_f32(0x08), 2f32.powi(-6));
+        assert_eq!(fp8.decode_f32(0x01), 2f32.powi(-9));
+
+        // 464 is halfway between 448 and the NaN code: the tie goes to the
+        // even 448, anything above overflows to NaN unless saturating.
+        let options = ConvertOptions::new();
+        let encode = |value: f32, options: &ConvertOptions| {
+            fp8.convert_from(value.to_bits() as u64, Format::BINARY32, options)
+        };
+        assert_eq!(encode(300.0, &options).bits, 0x79);
+        assert_eq!(encode(464.0, &options).bits, 0x7E);
+        let outcome = encode(465.0, &options);
+        assert_eq!(outcome.bits, 0x7F);
+        assert!(outcome.flags.overflow && outcome.flags.inexact);
+        assert_eq!(encode(-1000.0, &options).bits, 0xFF);
+        assert_eq!(encode(f32::INFINITY, &options).bits, 0x7F);
+        let saturate = options.overflow(Overflow::Saturate);
+        assert_eq!(encode(1000.0, &saturate).bits, 0x7E);
+        assert_eq!(encode(f32::NEG_INFINITY, &saturate).bits, 0xFE);
+        let toward_zero = options.rounding(RoundingMode::TowardZero);
+        assert_eq!(encode(1000.0, &toward_zero).bits, 0x7E);
+        assert_eq!(encode(f32::NAN, &options).bits, 0x7F);
+        let canonical = options.nan(NanConversion::Canonical);
+        assert_eq!(encode(-f32::NAN, &canonical).bits, 0x7F);
+    }
+}
@@ -0,0 +1,744 @@
+use crate::error::Error;
+use crate::ieee754::bignum::BigUint;
+use crate::ieee754::format::Format;
+use crate::ieee754::rounding::{Rounded, RoundingMode};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct DecimalParser;
+
+impl DecimalParser {
+    /// Parses a decimal (`-12.5e-3`) or hexadecimal floating-point literal
+    /// (`0x1.8p3`) straight into the bit pattern of `format`, rounding the
+    /// exact value of the input with `rounding`. `inf`, `infinity` and `nan`
+    /// are accepted in any case.
+    pub fn parse(input: &str, format: Format, rounding: RoundingMode) -> Result<u64, Error> {
+        Ok(Self::parse_rounded(input, format, rounding)?.bits)
+    }
+
+    pub(crate) fn parse_rounded(
+        input: &str,
+        format: Format,
+        rounding: RoundingMode,
+    ) -> Result<Rounded, Error> {
+        let mut scanner = Scanner::new(input);
+        scanner.skip_whitespace();
+        if scanner.peek().is_none() {
+            return Err(Error::EmptyValues);
+        }
+        let sign = match scanner.peek() {
+            Some('-') => {
+                scanner.next();
+                true
+            }
+            Some('+') => {
+                scanner.next();
+                false
+            }
+            _ => false,
+        };
+
+        let rest = scanner.rest().trim_end().to_ascii_lowercase();
+        if rest == "inf" || rest == "infinity" {
+            return Ok(exact(format.infinity(sign)));
+        }
+        if rest == "nan" {
+            let sign_bit = if sign { format.sign_mask() } else { 0 };
+            return Ok(exact(format.quiet_nan() | sign_bit));
+        }
+
+        let is_hex = rest.starts_with("0x");
+        if is_hex {
+            scanner.next();
+            scanner.next();
+        }
+        let (radix, exponent_marker) = if is_hex { (16, 'p') } else { (10, 'e') };
+
+        let mut digits = BigUint::zero();
+        let mut digit_count: usize = 0;
+        let mut fraction_digits: i64 = 0;
+        let mut seen_point = false;
+        while let Some(character) = scanner.peek() {
+            if character == '.' && !seen_point {
+                seen_point = true;
+            } else if let Some(digit) = character.to_digit(radix) {
+                digits.mul_small(radix);
+                digits.add_small(digit);
+                digit_count += 1;
+                if seen_point {
+                    fraction_digits += 1;
+                }
+            } else {
+                break;
+            }
+            scanner.next();
+        }
+        if digit_count == 0 {
+            return Err(scanner.unexpected());
+        }
+
+        let mut exponent: i64 = 0;
+        if scanner.peek().map(|c| c.to_ascii_lowercase()) == Some(exponent_marker) {
+            scanner.next();
+            let negative = match scanner.peek() {
+                Some('-') => {
+                    scanner.next();
+                    true
+                }
+                Some('+') => {
+                    scanner.next();
+                    false
+                }
+                _ => false,
+            };
+            let mut exponent_digits: usize = 0;
+            while let Some(digit) = scanner.peek().and_then(|c| c.to_digit(10)) {
+                exponent = (exponent * 10 + digit as i64).min(1 << 40);
+                exponent_digits += 1;
+                scanner.next();
+            }
+            if exponent_digits == 0 {
+                return Err(scanner.unexpected());
+            }
+            if negative {
+                exponent = -exponent;
+            }
+        }
+        scanner.skip_whitespace();
+        if scanner.peek().is_some() {
+            return Err(scanner.unexpected());
+        }
+
+        if digits.is_zero() {
+            return Ok(exact(format.zero(sign)));
+        }
+        if is_hex {
+            let binary_exponent = exponent - 4 * fraction_digits;
+            return Ok(pack(format, sign, digits, binary_exponent, false, rounding));
+        }
+
+        let decimal_exponent = exponent - fraction_digits;
+        // Skip hopeless cases before building huge powers of ten.
+        match magnitude_range(format, &digits, decimal_exponent) {
+            Ordering::Greater => {
+                return Ok(format.round_pack(sign, 1, format.max_exponent() + 1, false, rounding));
+            }
+            Ordering::Less => return Ok(tiny(format, sign, rounding)),
+            Ordering::Equal => {}
+        }
+
+        if decimal_exponent >= 0 {
+            digits.mul_pow(10, decimal_exponent as u32);
+            return Ok(pack(format, sign, digits, 0, false, rounding));
+        }
+        let divisor = BigUint::pow(10, (-decimal_exponent) as u32);
+        // Scale the numerator so the quotient carries a few bits more than
+        // the precision of the format; the remainder becomes the sticky bit.
+        let wanted = format.precision() as i64 + 3;
+        let shift = (wanted + divisor.bit_len() as i64 - digits.bit_len() as i64).max(0);
+        digits.shl(shift as u32);
+        let (quotient, remainder) = digits.div_rem(&divisor);
+        Ok(pack(
+            format,
+            sign,
+            quotient,
+            -shift,
+            !remainder.is_zero(),
+            rounding,
+        ))
+    }
+}
+
+fn exact(bits: u64) -> Rounded {
+    Rounded {
+        bits,
+        inexact: false,
+        overflow: false,
+        underflow: false,
+    }
+}
+
+/// A non-zero magnitude below half of the smallest subnormal.
+fn tiny(format: Format, sign: bool, rounding: RoundingMode) -> Rounded {
+    let exponent = format.min_exponent() - format.mantissa_bits() as i32 - 2;
+    format.round_pack(sign, 1, exponent, true, rounding)
+}
+
+/// Rounds `(significand + sticky) * 2^exponent` for arbitrarily large
+/// significands and exponents.
+pub(crate) fn pack(
+    format: Format,
+    sign: bool,
+    mut significand: BigUint,
+    mut exponent: i64,
+    mut sticky: bool,
+    rounding: RoundingMode,
+) -> Rounded {
+    let length = significand.bit_len();
+    if length > 128 {
+        let shift = length - 128;
+        sticky |= significand.any_low_bits(shift);
+        significand.shr(shift);
+        exponent += shift as i64;
+    }
+    let top = exponent + significand.bit_len() as i64 - 1;
+    if top > format.max_exponent() as i64 {
+        return format.round_pack(sign, 1, format.max_exponent() + 1, false, rounding);
+    }
+    if top < (format.min_exponent() - format.mantissa_bits() as i32) as i64 - 2 {
+        return tiny(format, sign, rounding);
+    }
+    format.round_pack(
+        sign,
+        significand.to_u128(),
+        exponent as i32,
+        sticky,
+        rounding,
+    )
+}
+
+/// Sign and unsigned part of a decimal literal. Hexadecimal floats are
+/// rejected: they carry no decimal digits to work with.
+/// Where `digits * 10^exponent` lies against the values of `format`, from
+/// a log10 estimate that is off by at most one: `Less` below the square of
+/// the smallest subnormal, `Greater` far above the largest finite value.
+pub(crate) fn magnitude_range(format: Format, digits: &BigUint, exponent: i64) -> Ordering {
+    let magnitude = (digits.bit_len() as i64 - 1) * 30103 / 100000 + exponent;
+    let lowest = (format.min_exponent() - format.mantissa_bits() as i32) as i64;
+    if magnitude > format.max_exponent() as i64 * 30103 / 100000 + 2 {
+        Ordering::Greater
+    } else if magnitude < 2 * lowest * 30103 / 100000 - 2 {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+pub(crate) fn split_decimal(input: &str) -> Result<(bool, &str), Error> {
+    if let Some(position) = input.find(['x', 'X']) {
+        return Err(Error::UnexpectedCharacter {
+            position,
+            character: input[position..].chars().next().unwrap_or('x'),
+        });
+    }
+    let text = input.trim();
+    Ok(match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    })
+}
+
+/// Digits and decimal exponent of an unsigned decimal literal that already
+/// parsed, without trailing zeros, e.g. `(125, -2)` for `1.250`.
+pub(crate) fn decimal_digits(unsigned: &str) -> (BigUint, i64) {
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+        None => (unsigned, ""),
+    };
+    let exponent: i64 = if exponent.is_empty() {
+        0
+    } else {
+        exponent.parse().unwrap_or(if exponent.starts_with('-') {
+            -(1 << 40)
+        } else {
+            1 << 40
+        })
+    };
+    let mut digits = BigUint::zero();
+    let mut fraction_digits: i64 = 0;
+    // Zeros are only multiplied in once a non-zero digit follows them.
+    let mut zeros: u32 = 0;
+    let mut seen_point = false;
+    for character in mantissa.chars() {
+        if character == '.' {
+            seen_point = true;
+        } else if let Some(digit) = character.to_digit(10) {
+            if digit == 0 {
+                zeros += 1;
+            } else {
+                digits.mul_pow(10, zeros + 1);
+                digits.add_small(digit);
+                zeros = 0;
+            }
+            if seen_point {
+                fraction_digits += 1;
+            }
+        }
+    }
+    (digits, exponent - fraction_digits + zeros as i64)
+}
+
+struct Scanner<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(input: &'a str) -> Scanner<'a> {
+        Scanner { input, position: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn next(&mut self) {
+        if let Some(character) = self.peek() {
+            self.position += character.len_utf8();
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn unexpected(&self) -> Error {
+        match self.peek() {
+            Some(character) => Error::UnexpectedCharacter {
+                position: self.position,
+                character,
+            },
+            None => Error::UnexpectedEnd {
+                position: self.position,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DecimalFormatter;
+
+impl DecimalFormatter {
+    /// Full decimal expansion of the value held by `bits`. Every finite
+    /// binary floating-point value has a terminating decimal expansion, so
+    /// nothing is rounded: `0.1` as binary64 prints as
+    /// `0.1000000000000000055511151231257827021181583404541015625`.
+    pub fn exact(format: Format, bits: u64) -> String {
+        let sign = if format.sign(bits) { "-" } else { "" };
+        if format.is_nan(bits) {
+            return "NaN".to_string();
+        }
+        if format.is_infinite(bits) {
+            return format!("{}inf", sign);
+        }
+        let (_, significand, exponent) = format.decompose(bits);
+        format!("{}{}", sign, Self::exact_magnitude(significand, exponent))
+    }
+
+    /// Exact decimal expansion of `significand * 2^exponent`.
+    pub(crate) fn exact_magnitude(significand: u64, exponent: i32) -> String {
+        let mut digits = BigUint::from_u64(significand);
+        if exponent >= 0 {
+            digits.shl(exponent as u32);
+            return digits.to_decimal_string();
+        }
+        // significand / 2^n == significand * 5^n / 10^n
+        let scale = (-exponent) as usize;
+        digits.mul_pow(5, scale as u32);
+        Self::fixed_point(&digits, scale)
+    }
+
+    /// `digits / 10^scale` in positional notation without trailing zeros.
+    pub(crate) fn fixed_point(digits: &BigUint, scale: usize) -> String {
+        let mut digits = digits.to_decimal_string();
+        if digits.len() <= scale {
+            digits.insert_str(0, &"0".repeat(scale + 1 - digits.len()));
+        }
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{}.{}", integer, fraction)
+        }
+    }
+
+    /// Shortest decimal string that parses back (round to nearest, ties to
+    /// even) to the same bits, in positional notation like `f64`'s `Display`.
+    pub fn shortest(format: Format, bits: u64) -> String {
+        if let Some(special) = Self::special(format, bits) {
+            return special;
+        }
+        let sign = if format.sign(bits) { "-" } else { "" };
+        if format.is_zero(bits) {
+            return format!("{}0", sign);
+        }
+        let (digits, point) = Self::shortest_digits(format, bits);
+        if point <= 0 {
+            format!("{}0.{}{}", sign, "0".repeat(-point as usize), digits)
+        } else if point as usize >= digits.len() {
+            format!(
+                "{}{}{}",
+                sign,
+                digits,
+                "0".repeat(point as usize - digits.len())
+            )
+        } else {
+            let (integer, fraction) = digits.split_at(point as usize);
+            format!("{}{}.{}", sign, integer, fraction)
+        }
+    }
+
+    /// Same digits as [`DecimalFormatter::shortest`] in scientific notation
+    /// like `f64`'s `LowerExp`, e.g. `1.5e-7`.
+    pub fn shortest_scientific(format: Format, bits: u64) -> String {
+        if let Some(special) = Self::special(format, bits) {
+            return special;
+        }
+        let sign = if format.sign(bits) { "-" } else { "" };
+        if format.is_zero(bits) {
+            return format!("{}0e0", sign);
+        }
+        let (digits, point) = Self::shortest_digits(format, bits);
+        let (first, rest) = digits.split_at(1);
+        if rest.is_empty() {
+            format!("{}{}e{}", sign, first, point - 1)
+        } else {
+            format!("{}{}.{}e{}", sign, first, rest, point - 1)
+        }
+    }
+
+    fn special(format: Format, bits: u64) -> Option<String> {
+        if format.is_nan(bits) {
+            Some("NaN".to_string())
+        } else if format.is_infinite(bits) {
+            Some(if format.sign(bits) { "-inf" } else { "inf" }.to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Free-format digit generation (Steele & White / Burger & Dybvig) for
+    /// a finite non-zero value. Returns the digits `d1 d2 ...` and the
+    /// position `k` of the decimal point so the value is `0.d1d2... * 10^k`.
+    pub(crate) fn shortest_digits(format: Format, bits: u64) -> (String, i32) {
+        let (_, significand, exponent) = format.decompose(bits);
+        let lowest_exponent = format.min_exponent() - format.mantissa_bits() as i32;
+        let hidden_bit = 1u64 << format.mantissa_bits();
+        // The gap below a power of two is half the gap above it, unless the
+        // value is the smallest normal number.
+        let uneven = significand == hidden_bit && exponent > lowest_exponent;
+        // Boundaries round to even, so they belong to this value only when
+        // its significand is even.
+        let inclusive = significand % 2 == 0;
+
+        let mut r = BigUint::from_u64(significand);
+        let mut s = BigUint::from_u64(1);
+        let mut m_plus = BigUint::from_u64(1);
+        let mut m_minus = BigUint::from_u64(1);
+        if exponent >= 0 {
+            r.shl(exponent as u32 + 1);
+            s.shl(1);
+            m_plus.shl(exponent as u32);
+            m_minus.shl(exponent as u32);
+        } else {
+            r.shl(1);
+            s.shl((1 - exponent) as u32);
+        }
+        if uneven {
+            r.shl(1);
+            s.shl(1);
+            m_plus.shl(1);
+        }
+
+        let length = 64 - significand.leading_zeros() as i32;
+        // ceil((exponent + length - 1) * log10(2)) without floating-point
+        // math; 78913 / 2^18 matches log10(2) well beyond the binary64 range.
+        let scale = exponent + length - 1;
+        let mut k = if scale == 0 {
+            0
+        } else {
+            ((scale * 78913) >> 18) + 1
+        };
+        if k >= 0 {
+            s.mul_pow(10, k as u32);
+        } else {
+            r.mul_pow(10, (-k) as u32);
+            m_plus.mul_pow(10, (-k) as u32);
+            m_minus.mul_pow(10, (-k) as u32);
+        }
+        let reaches = |r: &BigUint, m_plus: &BigUint, s: &BigUint| {
+            let mut high = r.clone();
+            high.add(m_plus);
+            match high.cmp(s) {
+                Ordering::Greater => true,
+                Ordering::Equal => inclusive,
+                Ordering::Less => false,
+            }
+        };
+        if reaches(&r, &m_plus, &s) {
+            k += 1;
+        } else {
+            r.mul_small(10);
+            m_plus.mul_small(10);
+            m_minus.mul_small(10);
+        }
+
+        let mut digits = String::new();
+        loop {
+            let mut digit: u8 = 0;
+            while r >= s {
+                r.sub(&s);
+                digit += 1;
+            }
+            let low = match r.cmp(&m_minus) {
+                Ordering::Less => true,
+                Ordering::Equal => inclusive,
+                Ordering::Greater => false,
+            };
+            let high = reaches(&r, &m_plus, &s);
+            if !low && !high {
+                digits.push((b'0' + digit) as char);
+                r.mul_small(10);
+                m_plus.mul_small(10);
+                m_minus.mul_small(10);
+                continue;
+            }
+            let mut twice = r.clone();
+            twice.shl(1);
+            if high && (!low || twice >= s) {
+                digit += 1;
+            }
+            digits.push((b'0' + digit) as char);
+            break;
+        }
+        (digits, k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754::testing::Xorshift;
+
+    fn parse(input: &str, format: Format) -> u64 {
+        DecimalParser::parse(input, format, RoundingMode::NearestTiesToEven).unwrap()
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse("0.1", Format::BINARY32), 0.1f32.to_bits() as u64);
+        assert_eq!(parse("0.1", Format::BINARY64), 0.1f64.to_bits());
+        assert_eq!(parse("-33.33333333", Format::BINARY32), 0xC2055555);
+        assert_eq!(parse("3.4028235e38", Format::BINARY32), 0x7F7FFFFF);
+        assert_eq!(parse("3.5e38", Format::BINARY32), 0x7F800000);
+        assert_eq!(parse("1e-45", Format::BINARY32), 0x00000001);
+        assert_eq!(parse("2.4703282292062328e-324", Format::BINARY64), 1);
+        assert_eq!(parse("2.4703282292062327e-324", Format::BINARY64), 0);
+        assert_eq!(parse("65504", Format::BINARY16), 0x7BFF);
+        assert_eq!(parse("65520", Format::BINARY16), 0x7C00);
+        assert_eq!(parse("1.0", Format::BFLOAT16), 0x3F80);
+        assert_eq!(parse("-0", Format::BINARY64), 0x8000000000000000);
+        assert_eq!(parse("-inf", Format::BINARY16), 0xFC00);
+        assert_eq!(parse("NaN", Format::BINARY32), 0x7FC00000);
+        assert_eq!(parse("1e99999999999", Format::BINARY64), 0x7FF0000000000000);
+        assert_eq!(parse("1e-99999999999", Format::BINARY64), 0);
+
+        // Exactly halfway between 1 and the next double, with a trailing
+        // non-zero digit far beyond double precision.
+        let long = format!(
+            "1.00000000000000011102230246251565404236316680908203125{}1",
+            "0".repeat(500)
+        );
+        assert_eq!(parse(&long, Format::BINARY64), 0x3FF0000000000001);
+        assert_eq!(
+            parse(
+                "1.00000000000000011102230246251565404236316680908203125",
+                Format::BINARY64
+            ),
+            0x3FF0000000000000
+        );
+
+        for value in [
+            "123456.789",
+            "1.7976931348623157e308",
+            "4.9e-324",
+            "0.3",
+            "2.2250738585072011e-308",
+        ] {
+            assert_eq!(
+                parse(value, Format::BINARY64),
+                value.parse::<f64>().unwrap().to_bits()
+            );
+            assert_eq!(
+                parse(value, Format::BINARY32),
+                value.parse::<f32>().unwrap().to_bits() as u64
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_float() {
+        assert_eq!(parse("0x1.8p3", Format::BINARY64), 12.0f64.to_bits());
+        assert_eq!(parse("-0x1.0aaaaap+5", Format::BINARY32), 0xC2055555);
+        assert_eq!(parse("0x1p-1074", Format::BINARY64), 1);
+        assert_eq!(parse("0X.8P1", Format::BINARY16), 0x3C00);
+    }
+
+    #[test]
+    fn test_parse_rounding_modes() {
+        let modes = [
+            (RoundingMode::NearestTiesToEven, 0x3DCCCCCD),
+            (RoundingMode::NearestTiesToAway, 0x3DCCCCCD),
+            (RoundingMode::TowardZero, 0x3DCCCCCC),
+            (RoundingMode::TowardPositive, 0x3DCCCCCD),
+            (RoundingMode::TowardNegative, 0x3DCCCCCC),
+        ];
+        for (mode, expected) in modes {
+            assert_eq!(
+                DecimalParser::parse("0.1", Format::BINARY32, mode).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            DecimalParser::parse("1e40", Format::BINARY32, RoundingMode::TowardZero).unwrap(),
+            0x7F7FFFFF
+        );
+        assert_eq!(
+            DecimalParser::parse("-1e-50", Format::BINARY32, RoundingMode::TowardNegative).unwrap(),
+            0x80000001
+        );
+        assert_eq!(
+            DecimalParser::parse(
+                "2.5",
+                Format::new(3, 1).unwrap(),
+                RoundingMode::NearestTiesToAway
+            )
+            .unwrap(),
+            0b01001
+        );
+    }
+
+    #[test]
+    fn test_exact_decimal() {
+        assert_eq!(
+            DecimalFormatter::exact(Format::BINARY64, 0.1f64.to_bits()),
+            "0.1000000000000000055511151231257827021181583404541015625"
+        );
+        assert_eq!(
+            DecimalFormatter::exact(Format::BINARY32, 0xC2055555),
+            "-33.333332061767578125"
+        );
+        assert_eq!(
+            DecimalFormatter::exact(Format::BINARY32, 1),
+            format!("0.{}{}", "0".repeat(44), "140129846432481707092372958328991613128026194187651577175706828388979108268586060148663818836212158203125")
+        );
+        assert_eq!(DecimalFormatter::exact(Format::BINARY16, 0x7BFF), "65504");
+        assert_eq!(DecimalFormatter::exact(Format::BINARY16, 0x8000), "-0");
+        assert_eq!(
+            DecimalFormatter::exact(Format::BINARY16, 0x3555),
+            "0.333251953125"
+        );
+        assert_eq!(DecimalFormatter::exact(Format::BINARY16, 0xFC00), "-inf");
+        assert_eq!(DecimalFormatter::exact(Format::BINARY16, 0x7E00), "NaN");
+        assert_eq!(
+            DecimalFormatter::exact(Format::BINARY64, f64::MAX.to_bits()),
+            format!("{:.0}", f64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_shortest_decimal() {
+        let mut random = Xorshift::new(0x2545F4914F6CDD1D);
+        for _ in 0..2000 {
+            let state = random.next_u64();
+            let value = f64::from_bits(state);
+            if value.is_finite() {
+                assert_eq!(
+                    DecimalFormatter::shortest(Format::BINARY64, state),
+                    value.to_string()
+                );
+                assert_eq!(
+                    DecimalFormatter::shortest_scientific(Format::BINARY64, state),
+                    format!("{:e}", value)
+                );
+            }
+            let value = f32::from_bits(state as u32);
+            if value.is_finite() {
+                let bits = state & 0xFFFFFFFF;
+                assert_eq!(
+                    DecimalFormatter::shortest(Format::BINARY32, bits),
+                    value.to_string()
+                );
+            }
+        }
+        for value in [
+            f64::MIN_POSITIVE,
+            5e-324,
+            f64::MAX,
+            1.0,
+            0.3,
+            123456.0,
+            -2.5e-8,
+        ] {
+            assert_eq!(
+                DecimalFormatter::shortest_scientific(Format::BINARY64, value.to_bits()),
+                format!("{:e}", value)
+            );
+        }
+        assert_eq!(
+            DecimalFormatter::shortest(Format::BINARY16, 0x3555),
+            "0.3333"
+        );
+        assert_eq!(
+            DecimalFormatter::shortest(Format::BINARY16, 0x7BFF),
+            "65500"
+        );
+        assert_eq!(
+            DecimalFormatter::shortest(Format::BINARY16, 0x0001),
+            "0.00000006"
+        );
+        assert_eq!(DecimalFormatter::shortest(Format::BFLOAT16, 0x3F81), "1.01");
+        assert_eq!(DecimalFormatter::shortest(Format::BINARY16, 0x8000), "-0");
+        assert_eq!(
+            DecimalFormatter::shortest_scientific(Format::BINARY16, 0xFC00),
+            "-inf"
+        );
+    }
+
+    #[test]
+    fn test_shortest_decimal_round_trip() {
+        let formats = [
+            Format::BINARY16,
+            Format::new(4, 3).unwrap(),
+            Format::new(5, 2).unwrap(),
+        ];
+        for format in formats {
+            for bits in 0..(1u64 << format.total_bits()) {
+                if format.is_nan(bits) {
+                    continue;
+                }
+                let text = DecimalFormatter::shortest(format, bits);
+                assert_eq!(parse(&text, format), bits, "{}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = DecimalParser::parse("1.5x", Format::BINARY32, RoundingMode::default());
+        assert!(matches!(
+            error,
+            Err(Error::UnexpectedCharacter {
+                position: 3,
+                character: 'x'
+            })
+        ));
+        let error = DecimalParser::parse("1e", Format::BINARY32, RoundingMode::default());
+        assert!(matches!(error, Err(Error::UnexpectedEnd { position: 2 })));
+        let error = DecimalParser::parse("  ", Format::BINARY32, RoundingMode::default());
+        assert!(matches!(error, Err(Error::EmptyValues)));
+        let error = DecimalParser::parse("-.", Format::BINARY32, RoundingMode::default());
+        assert!(matches!(error, Err(Error::UnexpectedEnd { position: 2 })));
+    }
+}
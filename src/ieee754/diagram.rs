@@ -0,0 +1,272 @@
+use alloc::{format, string::String, vec::Vec};
+
+use crate::ieee754::decimal::DecimalFormatter;
+use crate::ieee754::format::Format;
+
+const CELL: u32 = 18;
+const GAP: u32 = 8;
+const MARGIN: u32 = 10;
+const LINE: u32 = 20;
+const MIN_WIDTH: u32 = 480;
+
+const SIGN_FILL: &str = "#f4b6b6";
+const EXPONENT_FILL: &str = "#b6dcb6";
+const FRACTION_FILL: &str = "#b6cff4";
+
+/// Self-contained SVG and HTML diagrams of a bit pattern: one box per bit,
+/// grouped into labelled sign, exponent and fraction fields, followed by the
+/// biased and unbiased exponent and the reconstructed value. Colors and
+/// fonts are inline attributes, so the output needs no stylesheet.
+#[derive(Debug, Clone, Copy)]
+pub struct Diagram;
+
+impl Diagram {
+    pub fn svg(format: Format, bits: u64) -> String {
+        let bits = bits & format.bits_mask();
+        let total = format.total_bits();
+        let exponent_bits = format.exponent_bits();
+        let fields = [
+            ("sign", SIGN_FILL, 0, 1),
+            ("exponent", EXPONENT_FILL, 1, exponent_bits),
+            (
+                "fraction",
+                FRACTION_FILL,
+                1 + exponent_bits,
+                format.mantissa_bits(),
+            ),
+        ];
+        let rows = Self::rows(format, bits);
+        let width = (2 * MARGIN + total * CELL + 2 * GAP).max(MIN_WIDTH);
+        let height = 90 + rows.len() as u32 * LINE;
+        let x = |index: u32| {
+            let gaps = if index > exponent_bits {
+                2
+            } else if index >= 1 {
+                1
+            } else {
+                0
+            };
+            MARGIN + index * CELL + gaps * GAP
+        };
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"12\" \
+             role=\"img\">\n<title>{} 0x{:0digits$X}</title>\n",
+            format,
+            bits,
+            digits = total.div_ceil(4) as usize,
+        );
+        for (name, fill, start, length) in fields {
+            let left = x(start);
+            let right = x(start + length - 1) + CELL;
+            let center = (left + right) / 2;
+            // Bit numbers of the first and last bit of the field.
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"16\" text-anchor=\"middle\" fill=\"#555\">{}</text>\n",
+                left + CELL / 2,
+                total - 1 - start,
+            ));
+            if length > 1 {
+                svg.push_str(&format!(
+                    "<text x=\"{}\" y=\"16\" text-anchor=\"middle\" fill=\"#555\">{}</text>\n",
+                    right - CELL / 2,
+                    total - start - length,
+                ));
+            }
+            for index in start..start + length {
+                let bit = (bits >> (total - 1 - index)) & 1;
+                svg.push_str(&format!(
+                    "<rect x=\"{}\" y=\"22\" width=\"{CELL}\" height=\"24\" fill=\"{fill}\" \
+                     stroke=\"#333\"/>\n<text x=\"{}\" y=\"39\" text-anchor=\"middle\">{bit}</text>\n",
+                    x(index),
+                    x(index) + CELL / 2,
+                ));
+            }
+            svg.push_str(&format!(
+                "<path d=\"M{left} 52 v6 H{right} v-6\" fill=\"none\" stroke=\"#333\"/>\n\
+                 <text x=\"{center}\" y=\"72\" text-anchor=\"middle\">{name}</text>\n",
+            ));
+        }
+        for (i, (label, text)) in rows.iter().enumerate() {
+            let y = 100 + i as u32 * LINE;
+            svg.push_str(&format!(
+                "<text x=\"{MARGIN}\" y=\"{y}\" font-weight=\"bold\">{label}</text>\n\
+                 <text x=\"{}\" y=\"{y}\">{text}</text>\n",
+                MARGIN + 80,
+            ));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The SVG diagram inside a `<figure>`, captioned with the format, the
+    /// bits and the value.
+    pub fn html(format: Format, bits: u64) -> String {
+        let bits = bits & format.bits_mask();
+        format!(
+            "<figure class=\"ieee754-diagram\">\n{}<figcaption>{} 0x{:0digits$X} = {}</figcaption>\n</figure>\n",
+            Self::svg(format, bits),
+            format,
+            bits,
+            Self::value(format, bits),
+            digits = format.total_bits().div_ceil(4) as usize,
+        )
+    }
+
+    fn value(format: Format, bits: u64) -> String {
+        let shortest = DecimalFormatter::shortest(format, bits);
+        if shortest.len() > 24 {
+            DecimalFormatter::shortest_scientific(format, bits)
+        } else {
+            shortest
+        }
+    }
+
+    fn rows(format: Format, bits: u64) -> Vec<(&'static str, String)> {
+        let sign = format.sign(bits);
+        let biased_exponent = format.biased_exponent(bits);
+        let mantissa = format.mantissa(bits);
+        let exponent_field = Self::binary(biased_exponent, format.exponent_bits());
+        let fraction_field = Self::binary(mantissa, format.mantissa_bits());
+        let mut rows = Vec::new();
+        rows.push((
+            "sign",
+            format!(
+                "{} = {}",
+                sign as u8,
+                if sign { "negative" } else { "positive" }
+            ),
+        ));
+        if !format.is_finite(bits) {
+            rows.push((
+                "exponent",
+                format!(
+                    "{} = {}, reserved for {}",
+                    exponent_field,
+                    biased_exponent,
+                    if format.has_infinities() {
+                        "infinity and NaN"
+                    } else {
+                        "NaN"
+                    }
+                ),
+            ));
+            rows.push((
+                "fraction",
+                format!("{} = {}", fraction_field, format.classify(bits)),
+            ));
+            rows.push(("value", Self::value(format, bits)));
+            return rows;
+        }
+
+        let subnormal = biased_exponent == 0;
+        let unbiased = if subnormal {
+            format.min_exponent()
+        } else {
+            biased_exponent as i32 - format.bias()
+        };
+        rows.push((
+            "exponent",
+            if subnormal {
+                format!(
+                    "{} = 0, subnormal: 1 - {} = {}",
+                    exponent_field,
+                    format.bias(),
+                    unbiased
+                )
+            } else {
+                format!(
+                    "{} = {}, unbiased {} - {} = {}",
+                    exponent_field,
+                    biased_exponent,
+                    biased_exponent,
+                    format.bias(),
+                    unbiased
+                )
+            },
+        ));
+        let (_, significand, _) = format.decompose(bits);
+        let significand =
+            DecimalFormatter::exact_magnitude(significand, -(format.mantissa_bits() as i32));
+        rows.push((
+            "fraction",
+            format!(
+                "{}.{} (binary) = {}",
+                if subnormal { 0 } else { 1 },
+                fraction_field,
+                significand
+            ),
+        ));
+        rows.push((
+            "value",
+            format!(
+                "{}{} × 2^{} = {}",
+                if sign { "-" } else { "+" },
+                significand,
+                unbiased,
+                Self::value(format, bits)
+            ),
+        ));
+        rows
+    }
+
+    fn binary(value: u64, width: u32) -> String {
+        (0..width)
+            .rev()
+            .map(|shift| if (value >> shift) & 1 == 1 { '1' } else { '0' })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg() {
+        let svg = Diagram::svg(Format::BINARY16, 0xC100);
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"480\" height=\"170\" \
+             viewBox=\"0 0 480 170\" font-family=\"monospace\" font-size=\"12\" role=\"img\">\n\
+             <title>binary16 0xC100</title>\n"
+        ));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect").count(), 16);
+        assert_eq!(svg.matches(SIGN_FILL).count(), 1);
+        assert_eq!(svg.matches(EXPONENT_FILL).count(), 5);
+        assert_eq!(svg.matches(FRACTION_FILL).count(), 10);
+        // Bit 15 (the sign) is set and drawn first.
+        assert!(svg.contains(
+            "<rect x=\"10\" y=\"22\" width=\"18\" height=\"24\" fill=\"#f4b6b6\" stroke=\"#333\"/>\n\
+             <text x=\"19\" y=\"39\" text-anchor=\"middle\">1</text>\n"
+        ));
+        assert!(svg.contains(
+            ">exponent</text>\n<text x=\"90\" y=\"120\">10000 = 16, unbiased 16 - 15 = 1</text>"
+        ));
+        assert!(svg.contains(">1.0100000000 (binary) = 1.25</text>"));
+        assert!(svg.contains(">-1.25 × 2^1 = -2.5</text>"));
+
+        let svg = Diagram::svg(Format::BINARY32, 0x00000001);
+        assert!(svg.contains(">00000000 = 0, subnormal: 1 - 127 = -126</text>"));
+        assert!(
+            svg.contains(">0.00000000000000000000001 (binary) = 0.00000011920928955078125</text>")
+        );
+        assert!(svg.contains(" = 1e-45</text>"));
+
+        let svg = Diagram::svg(Format::BINARY64, 0x7FF4000000000000);
+        assert!(svg.contains("<title>binary64 0x7FF4000000000000</title>"));
+        assert!(svg.contains("width=\"1188\""));
+        assert!(svg.contains(" = signaling NaN</text>"));
+        assert!(svg.contains(">NaN</text>"));
+    }
+
+    #[test]
+    fn test_html() {
+        let format = Format::new(4, 3).unwrap();
+        let html = Diagram::html(format, 0x55);
+        assert!(html.starts_with("<figure class=\"ieee754-diagram\">\n<svg "));
+        assert!(html.contains("<title>e4m3 0x55</title>"));
+        assert!(html.ends_with("</svg>\n<figcaption>e4m3 0x55 = 13</figcaption>\n</figure>\n"));
+    }
+}
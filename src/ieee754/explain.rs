@@ -0,0 +1,1015 @@
+use crate::error::Error;
+use crate::ieee754::bignum::BigUint;
+use crate::ieee754::decimal::{
+    decimal_digits, magnitude_range, split_decimal, DecimalFormatter, DecimalParser,
+};
+use crate::ieee754::format::Format;
+use crate::ieee754::options::{FormatOptions, Grouping};
+use crate::ieee754::rounding::RoundingMode;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+
+/// Worked lines kept per step; longer walks end with a count of the rest.
+const MAX_LINES: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub title: String,
+    pub explanation: String,
+    /// Worked arithmetic, one operation per line.
+    pub lines: Vec<String>,
+    /// Bits produced by the step, if any.
+    pub bits: Option<String>,
+}
+
+impl Step {
+    fn new(title: &str, explanation: String) -> Step {
+        Step {
+            title: title.to_string(),
+            explanation,
+            lines: Vec::new(),
+            bits: None,
+        }
+    }
+
+    fn lines(mut self, lines: Vec<String>) -> Step {
+        self.lines = lines;
+        self
+    }
+
+    fn bits(mut self, bits: String) -> Step {
+        self.bits = Some(bits);
+        self
+    }
+}
+
+/// The steps taken to encode or decode one value, ending in `bits`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub title: String,
+    pub format: Format,
+    pub bits: u64,
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn to_text(&self) -> String {
+        let mut output = format!("{}\n", self.title);
+        for (number, step) in self.steps.iter().enumerate() {
+            output.push_str(&format!("\n{}. {}\n", number + 1, step.title));
+            output.push_str(&format!("   {}\n", step.explanation));
+            for line in &step.lines {
+                output.push_str(&format!("   {}\n", line));
+            }
+            if let Some(bits) = &step.bits {
+                output.push_str(&format!("   => {}\n", bits));
+            }
+        }
+        output
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut output = format!("## {}\n", self.title);
+        for (number, step) in self.steps.iter().enumerate() {
+            output.push_str(&format!("\n### {}. {}\n\n", number + 1, step.title));
+            output.push_str(&format!("{}\n", step.explanation));
+            if !step.lines.is_empty() {
+                output.push_str(&format!("\n```text\n{}\n```\n", step.lines.join("\n")));
+            }
+            if let Some(bits) = &step.bits {
+                output.push_str(&format!("\nResult: `{}`\n", bits));
+            }
+        }
+        output
+    }
+}
+
+#[derive(Debug)]
+pub struct Explainer;
+
+impl Explainer {
+    /// Walks through the textbook conversion of a decimal literal: integer
+    /// part division, fraction doubling, normalization, exponent biasing and
+    /// guard/round/sticky rounding with `rounding`.
+    pub fn encode(input: &str, format: Format, rounding: RoundingMode) -> Result<Trace, Error> {
+        let (sign, unsigned) = split_decimal(input)?;
+        let rounded = DecimalParser::parse_rounded(input, format, rounding)?;
+        let text = input.trim();
+        let mut trace = Trace {
+            title: format!("Encoding {} as {}", text, format),
+            format,
+            bits: rounded.bits,
+            steps: Vec::new(),
+        };
+        trace.steps.push(
+            Step::new(
+                "Sign",
+                format!(
+                    "{} is {}, so the sign bit is {}.",
+                    text,
+                    if sign { "negative" } else { "positive" },
+                    u8::from(sign)
+                ),
+            )
+            .bits(u8::from(sign).to_string()),
+        );
+
+        let bits = rounded.bits;
+        let lowercase = unsigned.to_ascii_lowercase();
+        if lowercase.starts_with("inf") || lowercase == "nan" {
+            let explanation = if !format.has_infinities() {
+                format!(
+                    "{} has no digits to convert. {} has no infinities; its only NaN has all \
+                     exponent and mantissa bits set.",
+                    if format.is_nan(bits) && lowercase == "nan" {
+                        "NaN"
+                    } else {
+                        "Infinity"
+                    },
+                    format
+                )
+            } else if format.is_nan(bits) {
+                "NaN has no digits to convert. It is stored with an all ones exponent and a \
+                 non-zero mantissa; setting the most significant mantissa bit makes it a quiet NaN."
+                    .to_string()
+            } else {
+                "Infinity has no digits to convert. It is stored with an all ones exponent and \
+                 an all zeros mantissa."
+                    .to_string()
+            };
+            trace.steps.push(Step::new("Special value", explanation));
+            trace.steps.push(assemble(format, bits, true));
+            return Ok(trace);
+        }
+        if rounded.overflow {
+            let max = format.max_finite(sign);
+            trace.steps.push(Step::new(
+                "Overflow",
+                format!(
+                    "{} is beyond the largest finite {} value {}. Rounding {} turns it into {}.",
+                    unsigned,
+                    format,
+                    DecimalFormatter::shortest(format, max & !format.sign_mask()),
+                    mode_name(rounding),
+                    if format.is_infinite(bits) {
+                        "infinity"
+                    } else if format.is_nan(bits) {
+                        "NaN, as the format has no infinities"
+                    } else {
+                        "the largest finite value"
+                    }
+                ),
+            ));
+            trace.steps.push(assemble(format, bits, false));
+            return Ok(trace);
+        }
+
+        let (digits, exponent) = decimal_digits(unsigned);
+        if digits.is_zero() {
+            trace.steps.push(Step::new(
+                "Zero",
+                "Zero is stored with all exponent and mantissa bits cleared; only the sign bit \
+                 tells +0 and -0 apart."
+                    .to_string(),
+            ));
+            trace.steps.push(assemble(format, bits, true));
+            return Ok(trace);
+        }
+        if magnitude_range(format, &digits, exponent) == Ordering::Less {
+            trace.steps.push(Step::new(
+                "Underflow",
+                format!(
+                    "{} is far below the smallest subnormal {} value {}. Every bit lands below \
+                     the mantissa and only sets the sticky bit, and rounding {} gives {}.",
+                    unsigned,
+                    format,
+                    DecimalFormatter::shortest(format, 1),
+                    mode_name(rounding),
+                    DecimalFormatter::shortest(format, bits & !format.sign_mask())
+                ),
+            ));
+            trace.steps.push(assemble(format, bits, false));
+            return Ok(trace);
+        }
+
+        let walk = Walk::new(digits, exponent, format);
+        trace.steps.push(walk.integer_step());
+        trace.steps.push(walk.fraction_step());
+        let Some(top) = walk.top else {
+            trace.steps.push(Step::new(
+                "Underflow",
+                format!(
+                    "No bit is set above the smallest subnormal {} value, so every bit only \
+                     sets the sticky bit.",
+                    format
+                ),
+            ));
+            trace.steps.push(assemble(format, bits, false));
+            return Ok(trace);
+        };
+        trace.steps.push(walk.normalize_step(top));
+        trace.steps.push(walk.exponent_step(top));
+        trace.steps.push(walk.rounding_step(top, sign, rounding));
+        trace.steps.push(assemble(format, bits, !rounded.inexact));
+        Ok(trace)
+    }
+
+    /// Walks through reading `bits` back: splitting the fields, removing
+    /// the exponent bias and scaling the significand.
+    pub fn decode(format: Format, bits: u64) -> Trace {
+        let bits = bits & format.bits_mask();
+        let hex = FormatOptions::new().prefix(true).format_hex(format, bits);
+        let mut trace = Trace {
+            title: format!("Decoding {} as {}", hex, format),
+            format,
+            bits,
+            steps: Vec::new(),
+        };
+        let sign = format.sign(bits);
+        let biased = format.biased_exponent(bits);
+        let mantissa = format.mantissa(bits);
+        let exponent_bits = format!(
+            "{:0width$b}",
+            biased,
+            width = format.exponent_bits() as usize
+        );
+        let mantissa_bits = format!(
+            "{:0width$b}",
+            mantissa,
+            width = format.mantissa_bits() as usize
+        );
+        trace.steps.push(
+            Step::new(
+                "Fields",
+                format!(
+                    "The {} bits split into 1 sign bit, {} exponent bits and {} mantissa bits.",
+                    format.total_bits(),
+                    format.exponent_bits(),
+                    format.mantissa_bits()
+                ),
+            )
+            .lines(vec![
+                format!("sign     {}", u8::from(sign)),
+                format!("exponent {}", exponent_bits),
+                format!("mantissa {}", mantissa_bits),
+            ])
+            .bits(
+                FormatOptions::new()
+                    .grouping(Grouping::Fields)
+                    .format_binary(format, bits),
+            ),
+        );
+        trace.steps.push(Step::new(
+            "Sign",
+            format!(
+                "The sign bit is {}, so the value is {}.",
+                u8::from(sign),
+                if sign { "negative" } else { "positive" }
+            ),
+        ));
+
+        if !format.is_finite(bits) {
+            let explanation = if !format.has_infinities() {
+                format!(
+                    "All exponent and mantissa bits set is the only NaN of {}, which has no \
+                     infinities.",
+                    format
+                )
+            } else if mantissa == 0 {
+                "An all ones exponent with an all zeros mantissa is infinity.".to_string()
+            } else {
+                let quiet = if format.is_signaling_nan(bits) {
+                    "clear, so it is a signaling NaN"
+                } else {
+                    "set, so it is a quiet NaN"
+                };
+                format!(
+                    "An all ones exponent with a non-zero mantissa is NaN. The most significant \
+                     mantissa bit is {}; the remaining bits are the payload.",
+                    quiet
+                )
+            };
+            trace.steps.push(
+                Step::new("Special value", explanation).bits(DecimalFormatter::exact(format, bits)),
+            );
+            return trace;
+        }
+
+        let (leading, exponent) = if biased == 0 {
+            trace.steps.push(
+                Step::new(
+                    "Exponent",
+                    format!(
+                        "A biased exponent of 0 marks a subnormal number or zero. The exponent is \
+                         fixed at the smallest normal exponent {} and there is no implicit \
+                         leading 1.",
+                        format.min_exponent()
+                    ),
+                )
+                .bits(format.min_exponent().to_string()),
+            );
+            (0, format.min_exponent())
+        } else {
+            let exponent = biased as i32 - format.bias();
+            trace.steps.push(
+                Step::new(
+                    "Exponent",
+                    format!(
+                        "The exponent field holds the exponent plus the bias {}.",
+                        format.bias()
+                    ),
+                )
+                .lines(vec![
+                    format!("{} = {}", exponent_bits, biased),
+                    format!("{} - {} = {}", biased, format.bias(), exponent),
+                ])
+                .bits(exponent.to_string()),
+            );
+            (1, exponent)
+        };
+
+        let significand = ((leading as u64) << format.mantissa_bits()) | mantissa;
+        let fraction_bits = mantissa_bits.trim_end_matches('0');
+        let binary = if fraction_bits.is_empty() {
+            leading.to_string()
+        } else {
+            format!("{}.{}", leading, fraction_bits)
+        };
+        let significand_value =
+            DecimalFormatter::exact_magnitude(significand, -(format.mantissa_bits() as i32));
+        trace.steps.push(
+            Step::new(
+                "Significand",
+                format!(
+                    "The mantissa bits are the fraction after the binary point, behind an \
+                     implicit leading {}.",
+                    leading
+                ),
+            )
+            .lines(vec![
+                format!(
+                    "{} + {} / 2^{} = {}",
+                    leading,
+                    mantissa,
+                    format.mantissa_bits(),
+                    significand_value
+                ),
+                format!("{} (binary) = {}", binary, significand_value),
+            ])
+            .bits(significand_value.clone()),
+        );
+
+        let sign_text = if sign { "-" } else { "" };
+        trace.steps.push(
+            Step::new(
+                "Value",
+                format!(
+                    "Scaling the significand by 2^{} gives the exact value. The shortest decimal \
+                     that reads back to the same bits is {}.",
+                    exponent,
+                    DecimalFormatter::shortest(format, bits)
+                ),
+            )
+            .lines(vec![format!(
+                "{}{} × 2^{} = {}",
+                sign_text,
+                significand_value,
+                exponent,
+                DecimalFormatter::exact(format, bits)
+            )])
+            .bits(DecimalFormatter::exact(format, bits)),
+        );
+        trace
+    }
+}
+
+/// The bits of a positive decimal value as produced by the textbook walk:
+/// integer part bits followed by the fraction bits needed for rounding.
+struct Walk {
+    format: Format,
+    integer: BigUint,
+    /// Fractional part as `numerator / 10^scale`.
+    numerator: BigUint,
+    scale: u32,
+    /// All bits, most significant first; `point` of them are integer bits.
+    bits: Vec<u8>,
+    point: usize,
+    /// Exponent of the leading 1 bit, if one was found.
+    top: Option<i32>,
+    /// Whether a non-zero fraction was left after the last doubling.
+    remainder: bool,
+    integer_lines: Vec<String>,
+    fraction_lines: Vec<String>,
+}
+
+impl Walk {
+    fn new(digits: BigUint, exponent: i64, format: Format) -> Walk {
+        let (integer, numerator, scale) = if exponent >= 0 {
+            let mut integer = digits;
+            integer.mul_pow(10, exponent as u32);
+            (integer, BigUint::zero(), 0)
+        } else {
+            let divisor = BigUint::pow(10, (-exponent) as u32);
+            let (integer, numerator) = digits.div_rem(&divisor);
+            (integer, numerator, (-exponent) as u32)
+        };
+        let mut walk = Walk {
+            format,
+            integer,
+            numerator,
+            scale,
+            bits: Vec::new(),
+            point: 0,
+            top: None,
+            remainder: false,
+            integer_lines: Vec::new(),
+            fraction_lines: Vec::new(),
+        };
+        walk.divide();
+        walk.double();
+        walk
+    }
+
+    fn divide(&mut self) {
+        let mut quotient = self.integer.clone();
+        let mut count = 0;
+        while !quotient.is_zero() {
+            let before = (count < MAX_LINES).then(|| quotient.to_decimal_string());
+            let remainder = quotient.div_small(2);
+            self.bits.push(remainder as u8);
+            if let Some(before) = before {
+                self.integer_lines.push(format!(
+                    "{} ÷ 2 = {} remainder {}",
+                    before,
+                    quotient.to_decimal_string(),
+                    remainder
+                ));
+            }
+            count += 1;
+        }
+        if count > MAX_LINES {
+            self.integer_lines
+                .push(format!("… {} more divisions", count - MAX_LINES));
+        }
+        self.bits.reverse();
+        self.point = self.bits.len();
+        if self.point > 0 {
+            self.top = Some(self.point as i32 - 1);
+        }
+    }
+
+    fn double(&mut self) {
+        let format = self.format;
+        let divisor = BigUint::pow(10, self.scale);
+        let mut fraction = self.numerator.clone();
+        let mut position: i32 = -1;
+        let mut count = 0;
+        while !fraction.is_zero() {
+            // Stop once the round bit, two places below the last mantissa
+            // bit, is known; anything further only feeds the sticky bit.
+            let limit = match self.top {
+                Some(top) => top.max(format.min_exponent()),
+                None => format.min_exponent(),
+            } - format.mantissa_bits() as i32
+                - 2;
+            if position < limit {
+                break;
+            }
+            let before = (count < MAX_LINES).then(|| fraction_text(0, &fraction, self.scale));
+            fraction.shl(1);
+            let bit = if fraction >= divisor {
+                fraction.sub(&divisor);
+                1
+            } else {
+                0
+            };
+            if let Some(before) = before {
+                self.fraction_lines.push(format!(
+                    "{} × 2 = {} → {}",
+                    before,
+                    fraction_text(bit, &fraction, self.scale),
+                    bit
+                ));
+            }
+            self.bits.push(bit);
+            if bit == 1 && self.top.is_none() {
+                self.top = Some(position);
+            }
+            position -= 1;
+            count += 1;
+        }
+        if count > MAX_LINES {
+            self.fraction_lines
+                .push(format!("… {} more doublings", count - MAX_LINES));
+        }
+        self.remainder = !fraction.is_zero();
+    }
+
+    /// Bit with weight `2^exponent`.
+    fn bit(&self, exponent: i32) -> u8 {
+        let index = self.point as i64 - 1 - exponent as i64;
+        if index < 0 {
+            return 0;
+        }
+        self.bits.get(index as usize).copied().unwrap_or(0)
+    }
+
+    fn bit_string(&self, from: i32, to: i32) -> String {
+        (to..=from)
+            .rev()
+            .map(|exponent| (b'0' + self.bit(exponent)) as char)
+            .collect()
+    }
+
+    fn integer_step(&self) -> Step {
+        if self.point == 0 {
+            return Step::new(
+                "Integer part",
+                "The integer part is 0, so it contributes no bits before the binary point."
+                    .to_string(),
+            )
+            .bits("0".to_string());
+        }
+        Step::new(
+            "Integer part",
+            format!(
+                "The integer part {} is divided by 2 until the quotient is 0. The remainders, \
+                 read from the last to the first, are its binary digits.",
+                self.integer.to_decimal_string()
+            ),
+        )
+        .lines(self.integer_lines.clone())
+        .bits(self.bit_string(self.point as i32 - 1, 0))
+    }
+
+    fn fraction_step(&self) -> Step {
+        if self.numerator.is_zero() {
+            return Step::new(
+                "Fractional part",
+                "The fractional part is 0, so it contributes no bits after the binary point."
+                    .to_string(),
+            );
+        }
+        let mut explanation = format!(
+            "The fractional part {} is doubled repeatedly. The digit before the point of each \
+             product is the next bit and only the fraction is carried on.",
+            fraction_text(0, &self.numerator, self.scale)
+        );
+        if self.remainder {
+            explanation.push_str(
+                " Doubling stops once the round bit is known; the fraction left over only \
+                 sets the sticky bit.",
+            );
+        } else {
+            explanation.push_str(" Doubling stops when the fraction reaches 0.");
+        }
+        let fraction_bits: String = self.bits[self.point..]
+            .iter()
+            .map(|bit| (b'0' + bit) as char)
+            .collect();
+        Step::new("Fractional part", explanation)
+            .lines(self.fraction_lines.clone())
+            .bits(if self.remainder {
+                format!("{}…", fraction_bits)
+            } else {
+                fraction_bits
+            })
+    }
+
+    fn normalize_step(&self, top: i32) -> Step {
+        let format = self.format;
+        let integer = if self.point == 0 {
+            "0".to_string()
+        } else {
+            self.bit_string(self.point as i32 - 1, 0)
+        };
+        let fraction = self.bit_string(-1, -((self.bits.len() - self.point) as i32));
+        let ellipsis = if self.remainder { "…" } else { "" };
+        let binary = if fraction.is_empty() {
+            integer
+        } else {
+            format!("{}.{}{}", integer, fraction, ellipsis)
+        };
+        let lowest = -((self.bits.len() - self.point) as i32);
+        if top >= format.min_exponent() {
+            let rest = self.bit_string(top - 1, lowest.min(top - 1));
+            let rest = rest.trim_end_matches('0');
+            let normalized = if rest.is_empty() && !self.remainder {
+                format!("1 × 2^{}", top)
+            } else {
+                format!("1.{}{} × 2^{}", rest, ellipsis, top)
+            };
+            let explanation = match top {
+                0 => "The value already has a single 1 in front of the binary point, so the \
+                      exponent is 0."
+                    .to_string(),
+                1 | -1 => format!(
+                    "Moving the binary point 1 place to the {} leaves a single 1 in front of \
+                     it; the exponent counts the moves.",
+                    if top > 0 { "left" } else { "right" }
+                ),
+                _ => format!(
+                    "Moving the binary point {} places to the {} leaves a single 1 in front of \
+                     it; the exponent counts the moves.",
+                    top.abs(),
+                    if top > 0 { "left" } else { "right" }
+                ),
+            };
+            Step::new("Normalize", explanation)
+                .lines(vec![format!("{} = {}", binary, normalized)])
+                .bits(normalized)
+        } else {
+            let emin = format.min_exponent();
+            let rest = self.bit_string(emin - 1, lowest.min(emin - 1));
+            let normalized = format!("0.{}{} × 2^{}", rest.trim_end_matches('0'), ellipsis, emin);
+            Step::new(
+                "Normalize",
+                format!(
+                    "The leading 1 has weight 2^{}, below the smallest normal exponent {}. The \
+                     value is written as 0.xxx × 2^{} instead and becomes subnormal.",
+                    top, emin, emin
+                ),
+            )
+            .lines(vec![format!("{} = {}", binary, normalized)])
+            .bits(normalized)
+        }
+    }
+
+    fn exponent_step(&self, top: i32) -> Step {
+        let format = self.format;
+        let width = format.exponent_bits() as usize;
+        if top < format.min_exponent() {
+            return Step::new(
+                "Exponent",
+                "Subnormal numbers store a biased exponent of 0.".to_string(),
+            )
+            .bits(format!("{:0width$b}", 0, width = width));
+        }
+        let biased = top + format.bias();
+        Step::new(
+            "Exponent",
+            format!(
+                "The bias {} is added so the exponent field is never negative.",
+                format.bias()
+            ),
+        )
+        .lines(vec![
+            format!("{} + {} = {}", top, format.bias(), biased),
+            format!("{} = {:0width$b}", biased, biased, width = width),
+        ])
+        .bits(format!("{:0width$b}", biased, width = width))
+    }
+
+    fn rounding_step(&self, top: i32, sign: bool, rounding: RoundingMode) -> Step {
+        let format = self.format;
+        let mantissa_bits = format.mantissa_bits();
+        let normal = top >= format.min_exponent();
+        let last = top.max(format.min_exponent()) - mantissa_bits as i32;
+        let mut kept: u64 = 0;
+        for exponent in (last..=top).rev() {
+            kept |= (self.bit(exponent) as u64) << (exponent - last);
+        }
+        let guard = self.bit(last - 1) == 1;
+        let round = self.bit(last - 2) == 1;
+        let lowest = self.point as i32 - self.bits.len() as i32;
+        let sticky = self.remainder || (lowest..last - 2).any(|exponent| self.bit(exponent) == 1);
+        let mantissa = kept & format.mantissa_mask();
+        let biased = if normal {
+            (top + format.bias()) as u64
+        } else {
+            0
+        };
+        let lsb = kept & 1 == 1;
+        let up = rounding.round_up(sign, lsb, guard, round || sticky);
+        let magnitude = ((biased << mantissa_bits) | mantissa) + u64::from(up);
+
+        let width = mantissa_bits as usize;
+        let mut lines = vec![
+            format!("mantissa {:0width$b}", mantissa, width = width),
+            format!(
+                "guard {}, round {}, sticky {}",
+                u8::from(guard),
+                u8::from(round),
+                u8::from(sticky)
+            ),
+        ];
+        let new_mantissa = magnitude & format.mantissa_mask();
+        if up {
+            lines.push(format!(
+                "{:0width$b} + 1 = {:0width$b}",
+                mantissa,
+                new_mantissa,
+                width = width
+            ));
+            if magnitude >> mantissa_bits != biased {
+                lines.push(format!(
+                    "the carry leaves the mantissa and raises the biased exponent to {}",
+                    magnitude >> mantissa_bits
+                ));
+            }
+        }
+        let explanation = if !guard && !round && !sticky {
+            "Only the mantissa bits are kept. Guard, round and sticky are all 0, so nothing is \
+             lost and the value is exact."
+                .to_string()
+        } else {
+            format!(
+                "Only the mantissa bits are kept. The guard bit is the first bit dropped, the \
+                 round bit the second and the sticky bit records whether any later bit is set. \
+                 {}",
+                rounding_reason(rounding, sign, lsb, guard, round || sticky, up)
+            )
+        };
+        Step::new("Round", explanation).lines(lines).bits(format!(
+            "{:0width$b}",
+            new_mantissa,
+            width = width
+        ))
+    }
+}
+
+fn assemble(format: Format, bits: u64, exact: bool) -> Step {
+    let value = DecimalFormatter::exact(format, bits);
+    let hex = FormatOptions::new().prefix(true).format_hex(format, bits);
+    let note = if format.is_nan(bits) {
+        String::new()
+    } else if exact {
+        format!(" It stores exactly {}.", value)
+    } else {
+        format!(
+            " It stores {}, which differs from the input by rounding.",
+            value
+        )
+    };
+    Step::new(
+        "Assemble",
+        format!(
+            "Sign, exponent and mantissa are written one after another, giving {}.{}",
+            hex, note
+        ),
+    )
+    .lines(vec![
+        format!("sign     {}", u8::from(format.sign(bits))),
+        format!(
+            "exponent {:0width$b}",
+            format.biased_exponent(bits),
+            width = format.exponent_bits() as usize
+        ),
+        format!(
+            "mantissa {:0width$b}",
+            format.mantissa(bits),
+            width = format.mantissa_bits() as usize
+        ),
+    ])
+    .bits(
+        FormatOptions::new()
+            .grouping(Grouping::Fields)
+            .format_binary(format, bits),
+    )
+}
+
+fn mode_name(rounding: RoundingMode) -> &'static str {
+    match rounding {
+        RoundingMode::NearestTiesToEven => "to nearest, ties to even,",
+        RoundingMode::NearestTiesToAway => "to nearest, ties away from zero,",
+        RoundingMode::TowardZero => "toward zero",
+        RoundingMode::TowardPositive => "toward positive infinity",
+        RoundingMode::TowardNegative => "toward negative infinity",
+    }
+}
+
+fn rounding_reason(
+    rounding: RoundingMode,
+    sign: bool,
+    lsb: bool,
+    guard: bool,
+    below: bool,
+    up: bool,
+) -> String {
+    let action = if up {
+        "the mantissa is rounded up"
+    } else {
+        "the extra bits are dropped"
+    };
+    let reason = match rounding {
+        RoundingMode::NearestTiesToEven | RoundingMode::NearestTiesToAway => {
+            if !guard {
+                "The guard bit is 0, so less than half a unit in the last place is dropped"
+                    .to_string()
+            } else if below {
+                "The guard bit and a later bit are set, so more than half a unit in the last place is dropped".to_string()
+            } else if rounding == RoundingMode::NearestTiesToEven {
+                format!(
+                    "Exactly half a unit in the last place is dropped and ties go to the even \
+                     neighbour; the last kept bit is {}",
+                    u8::from(lsb)
+                )
+            } else {
+                "Exactly half a unit in the last place is dropped and ties go away from zero"
+                    .to_string()
+            }
+        }
+        RoundingMode::TowardZero => "Rounding toward zero never increments".to_string(),
+        RoundingMode::TowardPositive | RoundingMode::TowardNegative => format!(
+            "Rounding {} moves {} values away from zero whenever a dropped bit is set",
+            mode_name(rounding),
+            if sign { "negative" } else { "positive" }
+        ),
+    };
+    format!("{}, so {}.", reason, action)
+}
+
+/// `bit.<fraction digits>` for `numerator / 10^scale`, e.g. `1.6`.
+fn fraction_text(bit: u8, numerator: &BigUint, scale: u32) -> String {
+    if numerator.is_zero() {
+        return bit.to_string();
+    }
+    let digits = numerator.to_decimal_string();
+    let padding = "0".repeat(scale as usize - digits.len());
+    let fraction = format!("{}{}", padding, digits);
+    format!("{}.{}", bit, fraction.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754::testing::Xorshift;
+
+    #[test]
+    fn test_encode_trace() {
+        let trace =
+            Explainer::encode("13.1", Format::new(4, 3).unwrap(), RoundingMode::default()).unwrap();
+        assert_eq!(
+            trace.to_text(),
+            "Encoding 13.1 as e4m3
+
+1. Sign
+   13.1 is positive, so the sign bit is 0.
+   => 0
+
+2. Integer part
+   The integer part 13 is divided by 2 until the quotient is 0. The remainders, read from the last to the first, are its binary digits.
+   13 ÷ 2 = 6 remainder 1
+   6 ÷ 2 = 3 remainder 0
+   3 ÷ 2 = 1 remainder 1
+   1 ÷ 2 = 0 remainder 1
+   => 1101
+
+3. Fractional part
+   The fractional part 0.1 is doubled repeatedly. The digit before the point of each product is the next bit and only the fraction is carried on. Doubling stops once the round bit is known; the fraction left over only sets the sticky bit.
+   0.1 × 2 = 0.2 → 0
+   0.2 × 2 = 0.4 → 0
+   => 00…
+
+4. Normalize
+   Moving the binary point 3 places to the left leaves a single 1 in front of it; the exponent counts the moves.
+   1101.00… = 1.101… × 2^3
+   => 1.101… × 2^3
+
+5. Exponent
+   The bias 7 is added so the exponent field is never negative.
+   3 + 7 = 10
+   10 = 1010
+   => 1010
+
+6. Round
+   Only the mantissa bits are kept. The guard bit is the first bit dropped, the round bit the second and the sticky bit records whether any later bit is set. The guard bit is 0, so less than half a unit in the last place is dropped, so the extra bits are dropped.
+   mantissa 101
+   guard 0, round 0, sticky 1
+   => 101
+
+7. Assemble
+   Sign, exponent and mantissa are written one after another, giving 0x55. It stores 13, which differs from the input by rounding.
+   sign     0
+   exponent 1010
+   mantissa 101
+   => 0 1010 101
+"
+        );
+        assert_eq!(trace.bits, 0x55);
+
+        let trace = Explainer::encode("-0.1", Format::BINARY32, RoundingMode::default()).unwrap();
+        let markdown = trace.to_markdown();
+        assert!(markdown.starts_with("## Encoding -0.1 as binary32\n\n### 1. Sign\n"));
+        assert!(markdown.contains("\n```text\n0.1 × 2 = 0.2 → 0\n0.2 × 2 = 0.4 → 0\n"));
+        assert!(markdown.contains("guard 1, round 1, sticky 1\n"));
+        assert!(markdown.contains("\nResult: `1 01111011 10011001100110011001101`\n"));
+        assert_eq!(trace.bits, 0xBDCCCCCD);
+
+        let trace = Explainer::encode("1e-45", Format::BINARY32, RoundingMode::default()).unwrap();
+        assert!(trace.steps[3].explanation.contains("becomes subnormal"));
+        assert_eq!(trace.bits, 1);
+        assert!(Explainer::encode("0x1p3", Format::BINARY32, RoundingMode::default()).is_err());
+    }
+
+    #[test]
+    fn test_encode_matches_parser() {
+        let mut random = Xorshift::new(0x243F6A8885A308D3);
+        let mut inputs: Vec<String> = [
+            "0",
+            "-0",
+            "inf",
+            "-nan",
+            "1e39",
+            "1e-50",
+            "2.5",
+            "3.5",
+            "65504",
+            "65520",
+            "0.000030517578125",
+            "5.9604645e-8",
+            "2.98e-8",
+            "1e10",
+            "123456789",
+        ]
+        .iter()
+        .map(|input| input.to_string())
+        .collect();
+        for _ in 0..300 {
+            let state = random.next_u64();
+            let digits = state % 1_000_000_007;
+            let exponent = (state >> 40) as i64 % 50 - 30;
+            inputs.push(format!("{}e{}", digits, exponent));
+        }
+        for format in [
+            Format::BINARY16,
+            Format::BFLOAT16,
+            Format::BINARY32,
+            Format::BINARY64,
+            Format::new(4, 3).unwrap(),
+        ] {
+            for rounding in [
+                RoundingMode::NearestTiesToEven,
+                RoundingMode::NearestTiesToAway,
+                RoundingMode::TowardZero,
+                RoundingMode::TowardPositive,
+                RoundingMode::TowardNegative,
+            ] {
+                for input in &inputs {
+                    let trace = Explainer::encode(input, format, rounding).unwrap();
+                    let expected = DecimalParser::parse(input, format, rounding).unwrap();
+                    assert_eq!(trace.bits, expected, "{} {} {:?}", input, format, rounding);
+                    let last = trace.steps.last().unwrap();
+                    assert_eq!(
+                        last.bits,
+                        Some(
+                            FormatOptions::new()
+                                .grouping(Grouping::Fields)
+                                .format_binary(format, expected)
+                        )
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_trace() {
+        let trace = Explainer::decode(Format::BINARY16, 0xC500);
+        assert_eq!(
+            trace.to_text(),
+            "Decoding 0xC500 as binary16
+
+1. Fields
+   The 16 bits split into 1 sign bit, 5 exponent bits and 10 mantissa bits.
+   sign     1
+   exponent 10001
+   mantissa 0100000000
+   => 1 10001 0100000000
+
+2. Sign
+   The sign bit is 1, so the value is negative.
+
+3. Exponent
+   The exponent field holds the exponent plus the bias 15.
+   10001 = 17
+   17 - 15 = 2
+   => 2
+
+4. Significand
+   The mantissa bits are the fraction after the binary point, behind an implicit leading 1.
+   1 + 256 / 2^10 = 1.25
+   1.01 (binary) = 1.25
+   => 1.25
+
+5. Value
+   Scaling the significand by 2^2 gives the exact value. The shortest decimal that reads back to the same bits is -5.
+   -1.25 × 2^2 = -5
+   => -5
+"
+        );
+        let trace = Explainer::decode(Format::BINARY32, 1);
+        assert!(trace.steps[2].explanation.contains("subnormal"));
+        assert_eq!(
+            trace.steps.last().unwrap().bits.as_deref(),
+            Some(DecimalFormatter::exact(Format::BINARY32, 1).as_str())
+        );
+        let trace = Explainer::decode(Format::BINARY16, 0x7D00);
+        assert!(trace.steps[2].explanation.contains("signaling NaN"));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::ieee754::format::Format;
+
+/// GPU style subnormal handling. Denormals-are-zero (DAZ) reads subnormal
+/// inputs as zero and flush-to-zero (FTZ) replaces subnormal results with
+/// zero; both keep the sign. For other operations, such as the arithmetic on
+/// [`Format`], pass the operands through [`FlushMode::input`] and the result
+/// through [`FlushMode::output`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FlushMode {
+    flush_to_zero: bool,
+    denormals_are_zero: bool,
+}
+
+/// A value and whether a subnormal was replaced by zero to produce it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flushed<T> {
+    pub value: T,
+    pub flushed: bool,
+}
+
+impl FlushMode {
+    /// Subnormals are kept, as IEEE 754 requires.
+    pub const IEEE: FlushMode = FlushMode {
+        flush_to_zero: false,
+        denormals_are_zero: false,
+    };
+    /// Both FTZ and DAZ, the usual setting of GPU kernels built with fast
+    /// math.
+    pub const FTZ_DAZ: FlushMode = FlushMode {
+        flush_to_zero: true,
+        denormals_are_zero: true,
+    };
+
+    pub const fn new() -> FlushMode {
+        FlushMode::IEEE
+    }
+
+    pub const fn flush_to_zero(mut self, flush_to_zero: bool) -> FlushMode {
+        self.flush_to_zero = flush_to_zero;
+        self
+    }
+
+    pub const fn denormals_are_zero(mut self, denormals_are_zero: bool) -> FlushMode {
+        self.denormals_are_zero = denormals_are_zero;
+        self
+    }
+
+    /// Applies DAZ to an encoding that is about to be read.
+    pub const fn input(&self, format: Format, bits: u64) -> Flushed<u64> {
+        Self::flush(self.denormals_are_zero, format, bits)
+    }
+
+    /// Applies FTZ to an encoding that was just produced.
+    pub const fn output(&self, format: Format, bits: u64) -> Flushed<u64> {
+        Self::flush(self.flush_to_zero, format, bits)
+    }
+
+    const fn flush(enabled: bool, format: Format, bits: u64) -> Flushed<u64> {
+        if enabled && format.is_subnormal(bits) {
+            Flushed {
+                value: format.zero(format.sign(bits)),
+                flushed: true,
+            }
+        } else {
+            Flushed {
+                value: bits,
+                flushed: false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::ieee754::{IEEE754_32bit, IEEE754_64bit};
+
+    #[test]
+    fn test_flush_mode() {
+        let half = Format::BINARY16;
+        let flushed = |value| Flushed {
+            value,
+            flushed: true,
+        };
+        let kept = |value| Flushed {
+            value,
+            flushed: false,
+        };
+        assert_eq!(FlushMode::FTZ_DAZ.input(half, 0x83FF), flushed(0x8000));
+        assert_eq!(FlushMode::FTZ_DAZ.output(half, 0x0001), flushed(0x0000));
+        assert_eq!(FlushMode::FTZ_DAZ.input(half, 0x0400), kept(0x0400));
+        assert_eq!(FlushMode::FTZ_DAZ.input(half, 0x8000), kept(0x8000));
+        assert_eq!(FlushMode::IEEE.input(half, 0x0001), kept(0x0001));
+
+        let ftz = FlushMode::new().flush_to_zero(true);
+        assert_eq!(ftz.input(half, 0x0001), kept(0x0001));
+        assert_eq!(ftz.output(half, 0x0001), flushed(0x0000));
+        let daz = FlushMode::new().denormals_are_zero(true);
+        assert_eq!(daz.input(Format::BFLOAT16, 0x8001), flushed(0x8000));
+        assert_eq!(daz.output(Format::BFLOAT16, 0x8001), kept(0x8001));
+    }
+
+    #[test]
+    fn test_decode_encode_with() {
+        let tiny = -f32::MIN_POSITIVE / 2.0;
+        let mode = FlushMode::FTZ_DAZ;
+        let decoded = IEEE754_32bit::decode_with(tiny.to_bits(), mode).unwrap();
+        assert_eq!(decoded.value.to_bits(), (-0.0f32).to_bits());
+        assert!(decoded.flushed);
+        let encoded = IEEE754_32bit::encode_with(tiny, mode);
+        assert_eq!(encoded.value, 0x80000000);
+        assert!(encoded.flushed);
+        let encoded = IEEE754_32bit::encode_with(tiny, FlushMode::IEEE);
+        assert_eq!(encoded.value, tiny.to_bits());
+        assert!(!encoded.flushed);
+        assert_eq!(
+            IEEE754_32bit::decode_with(0x7F800000, mode),
+            Err(Error::ExponentAll1s)
+        );
+
+        let decoded = IEEE754_64bit::decode_with(1, mode).unwrap();
+        assert_eq!(decoded.value.to_bits(), 0);
+        assert!(decoded.flushed);
+        let decoded = IEEE754_64bit::decode_with(1.5f64.to_bits(), mode).unwrap();
+        assert_eq!(decoded.value, 1.5);
+        assert!(!decoded.flushed);
+        let encoded = IEEE754_64bit::encode_with(-f64::MIN_POSITIVE / 4.0, mode);
+        assert_eq!(encoded.value, 0x8000000000000000);
+        assert!(encoded.flushed);
+    }
+}
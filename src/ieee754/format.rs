@@ -0,0 +1,341 @@
+use crate::error::Error;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Format {
+    exponent_bits: u32,
+    mantissa_bits: u32,
+    finite_only: bool,
+}
+
+impl Format {
+    pub const BINARY16: Format = Format {
+        exponent_bits: 5,
+        mantissa_bits: 10,
+        finite_only: false,
+    };
+    pub const BFLOAT16: Format = Format {
+        exponent_bits: 8,
+        mantissa_bits: 7,
+        finite_only: false,
+    };
+    pub const BINARY32: Format = Format {
+        exponent_bits: 8,
+        mantissa_bits: 23,
+        finite_only: false,
+    };
+    pub const BINARY64: Format = Format {
+        exponent_bits: 11,
+        mantissa_bits: 52,
+        finite_only: false,
+    };
+    /// The FP8 E4M3 format of the OCP 8-bit floating point specification, as
+    /// used by accelerators: no infinities, a largest finite value of 448 and
+    /// `S.1111.111` as its only NaN. See [`Format::new_finite`].
+    pub const E4M3FN: Format = Format {
+        exponent_bits: 4,
+        mantissa_bits: 3,
+        finite_only: true,
+    };
+
+    /// Custom IEEE 754 style format, e.g. a minifloat. The whole encoding
+    /// (sign, exponent and mantissa) has to fit in 64 bits.
+    pub fn new(exponent_bits: u32, mantissa_bits: u32) -> Result<Format, Error> {
+        if !(2..=15).contains(&exponent_bits)
+            || mantissa_bits == 0
+            || 1 + exponent_bits + mantissa_bits > 64
+        {
+            return Err(Error::InvalidFormat {
+                exponent_bits,
+                mantissa_bits,
+            });
+        }
+        Ok(Format {
+            exponent_bits,
+            mantissa_bits,
+            finite_only: false,
+        })
+    }
+
+    /// Extended range format without infinities, like the FP8 E4M3 of
+    /// accelerators ([`Format::E4M3FN`]). The all ones exponent holds normal
+    /// numbers, except for an all ones mantissa, which is the only NaN (of
+    /// either sign). Results that overflow become that NaN, or the largest
+    /// finite value when rounding toward zero or converting with
+    /// [`Overflow::Saturate`](crate::ieee754::Overflow::Saturate).
+    pub fn new_finite(exponent_bits: u32, mantissa_bits: u32) -> Result<Format, Error> {
+        let format = Format::new(exponent_bits, mantissa_bits)?;
+        Ok(Format {
+            finite_only: true,
+            ..format
+        })
+    }
+
+    pub const fn exponent_bits(&self) -> u32 {
+        self.exponent_bits
+    }
+
+    pub const fn mantissa_bits(&self) -> u32 {
+        self.mantissa_bits
+    }
+
+    /// Whether the format encodes infinities, false for [`Format::new_finite`].
+    pub const fn has_infinities(&self) -> bool {
+        !self.finite_only
+    }
+
+    pub const fn total_bits(&self) -> u32 {
+        1 + self.exponent_bits + self.mantissa_bits
+    }
+
+    /// Number of significand bits including the implicit leading bit.
+    pub const fn precision(&self) -> u32 {
+        self.mantissa_bits + 1
+    }
+
+    pub const fn bias(&self) -> i32 {
+        (1 << (self.exponent_bits - 1)) - 1
+    }
+
+    pub const fn min_exponent(&self) -> i32 {
+        1 - self.bias()
+    }
+
+    pub const fn max_exponent(&self) -> i32 {
+        if self.finite_only {
+            self.bias() + 1
+        } else {
+            self.bias()
+        }
+    }
+
+    pub const fn max_biased_exponent(&self) -> u64 {
+        (1 << self.exponent_bits) - 1
+    }
+
+    pub const fn sign_mask(&self) -> u64 {
+        1 << (self.exponent_bits + self.mantissa_bits)
+    }
+
+    pub const fn exponent_mask(&self) -> u64 {
+        self.max_biased_exponent() << self.mantissa_bits
+    }
+
+    pub const fn mantissa_mask(&self) -> u64 {
+        (1 << self.mantissa_bits) - 1
+    }
+
+    pub const fn bits_mask(&self) -> u64 {
+        self.sign_mask() | self.exponent_mask() | self.mantissa_mask()
+    }
+
+    pub const fn sign(&self, bits: u64) -> bool {
+        bits & self.sign_mask() != 0
+    }
+
+    pub const fn biased_exponent(&self, bits: u64) -> u64 {
+        (bits & self.exponent_mask()) >> self.mantissa_bits
+    }
+
+    pub const fn mantissa(&self, bits: u64) -> u64 {
+        bits & self.mantissa_mask()
+    }
+
+    pub const fn compose(&self, sign: bool, biased_exponent: u64, mantissa: u64) -> u64 {
+        let sign_bit = if sign { self.sign_mask() } else { 0 };
+        sign_bit
+            | ((biased_exponent << self.mantissa_bits) & self.exponent_mask())
+            | (mantissa & self.mantissa_mask())
+    }
+
+    pub const fn zero(&self, sign: bool) -> u64 {
+        self.compose(sign, 0, 0)
+    }
+
+    /// Formats without infinities return their NaN, which is also where
+    /// their overflowing results go.
+    pub const fn infinity(&self, sign: bool) -> u64 {
+        if self.finite_only {
+            return self.compose(sign, self.max_biased_exponent(), self.mantissa_mask());
+        }
+        self.compose(sign, self.max_biased_exponent(), 0)
+    }
+
+    pub const fn max_finite(&self, sign: bool) -> u64 {
+        if self.finite_only {
+            return self.compose(sign, self.max_biased_exponent(), self.mantissa_mask() - 1);
+        }
+        self.compose(sign, self.max_biased_exponent() - 1, self.mantissa_mask())
+    }
+
+    /// Default quiet NaN: positive sign and only the most significant
+    /// mantissa bit set, or the single NaN of a format without infinities.
+    pub const fn quiet_nan(&self) -> u64 {
+        if self.finite_only {
+            return self.infinity(false);
+        }
+        self.compose(
+            false,
+            self.max_biased_exponent(),
+            1 << (self.mantissa_bits - 1),
+        )
+    }
+
+    /// `bits` with the quiet bit set, for NaNs returned by operations.
+    pub(crate) const fn quiet(&self, bits: u64) -> u64 {
+        (bits & self.bits_mask()) | self.quiet_nan()
+    }
+
+    pub const fn is_nan(&self, bits: u64) -> bool {
+        if self.finite_only {
+            return bits & (self.exponent_mask() | self.mantissa_mask())
+                == self.exponent_mask() | self.mantissa_mask();
+        }
+        self.biased_exponent(bits) == self.max_biased_exponent() && self.mantissa(bits) != 0
+    }
+
+    pub const fn is_infinite(&self, bits: u64) -> bool {
+        !self.finite_only
+            && self.biased_exponent(bits) == self.max_biased_exponent()
+            && self.mantissa(bits) == 0
+    }
+
+    pub const fn is_finite(&self, bits: u64) -> bool {
+        !self.is_nan(bits) && !self.is_infinite(bits)
+    }
+
+    pub const fn is_zero(&self, bits: u64) -> bool {
+        bits & !self.sign_mask() & self.bits_mask() == 0
+    }
+
+    pub const fn is_subnormal(&self, bits: u64) -> bool {
+        self.biased_exponent(bits) == 0 && self.mantissa(bits) != 0
+    }
+
+    /// Always false for formats without infinities: their only NaN is quiet.
+    pub const fn is_signaling_nan(&self, bits: u64) -> bool {
+        !self.finite_only
+            && self.is_nan(bits)
+            && self.mantissa(bits) & (1 << (self.mantissa_bits - 1)) == 0
+    }
+
+    pub const fn classify(&self, bits: u64) -> Class {
+        if self.is_nan(bits) {
+            if self.is_signaling_nan(bits) {
+                Class::SignalingNaN
+            } else {
+                Class::QuietNaN
+            }
+        } else if self.is_infinite(bits) {
+            Class::Infinite
+        } else if self.is_zero(bits) {
+            Class::Zero
+        } else if self.is_subnormal(bits) {
+            Class::Subnormal
+        } else {
+            Class::Normal
+        }
+    }
+
+    /// Splits a finite encoding into `(sign, significand, exponent)` so that
+    /// its magnitude is exactly `significand * 2^exponent`.
+    pub const fn decompose(&self, bits: u64) -> (bool, u64, i32) {
+        let biased_exponent = self.biased_exponent(bits);
+        let mantissa = self.mantissa(bits);
+        let lsb_exponent = self.min_exponent() - self.mantissa_bits as i32;
+        if biased_exponent == 0 {
+            (self.sign(bits), mantissa, lsb_exponent)
+        } else {
+            (
+                self.sign(bits),
+                mantissa | (1 << self.mantissa_bits),
+                lsb_exponent + biased_exponent as i32 - 1,
+            )
+        }
+    }
+}
+
+/// `binary16`, `bfloat16`, `binary32` and `binary64` by name, any other
+/// format as `e<exponent bits>m<mantissa bits>`, e.g. `e4m3`, with an `fn`
+/// suffix for formats without infinities.
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Format::BINARY16 => f.write_str("binary16"),
+            Format::BFLOAT16 => f.write_str("bfloat16"),
+            Format::BINARY32 => f.write_str("binary32"),
+            Format::BINARY64 => f.write_str("binary64"),
+            _ if self.finite_only => write!(f, "e{}m{}fn", self.exponent_bits, self.mantissa_bits),
+            _ => write!(f, "e{}m{}", self.exponent_bits, self.mantissa_bits),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Class {
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    QuietNaN,
+    SignalingNaN,
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Class::Zero => "zero",
+            Class::Subnormal => "subnormal",
+            Class::Normal => "normal",
+            Class::Infinite => "infinite",
+            Class::QuietNaN => "quiet NaN",
+            Class::SignalingNaN => "signaling NaN",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification() {
+        let format = Format::BINARY16;
+        assert_eq!(format.bias(), 15);
+        assert_eq!(format.compose(false, 15, 0), 0x3C00);
+        assert!(format.is_zero(format.zero(true)));
+        assert!(format.sign(format.zero(true)));
+        assert!(format.is_infinite(format.infinity(false)));
+        assert!(format.is_nan(format.quiet_nan()));
+        assert!(format.is_subnormal(0x0001));
+        assert!(format.is_finite(format.max_finite(true)));
+        assert_eq!(format.decompose(0xC000), (true, 0x400, -9));
+        assert_eq!(format.classify(0x8000), Class::Zero);
+        assert_eq!(format.classify(0x03FF), Class::Subnormal);
+        assert_eq!(format.classify(0xBC00), Class::Normal);
+        assert_eq!(format.classify(0xFC00), Class::Infinite);
+        assert_eq!(format.classify(0x7E00), Class::QuietNaN);
+        assert_eq!(format.classify(0x7D00), Class::SignalingNaN);
+
+        let fp8 = Format::E4M3FN;
+        assert_eq!(Format::new_finite(4, 3), Ok(fp8));
+        assert_ne!(Format::new(4, 3), Ok(fp8));
+        assert_eq!(fp8.to_string(), "e4m3fn");
+        assert!(!fp8.has_infinities());
+        assert_eq!(fp8.max_exponent(), 8);
+        assert_eq!(fp8.max_finite(false), 0x7E);
+        assert_eq!(fp8.quiet_nan(), 0x7F);
+        assert_eq!(fp8.infinity(true), 0xFF);
+        assert_eq!(fp8.classify(0x78), Class::Normal);
+        assert_eq!(fp8.classify(0xFF), Class::QuietNaN);
+        assert!(!fp8.is_infinite(0x78) && fp8.is_finite(0x7E) && !fp8.is_finite(0x7F));
+        assert_eq!(
+            Format::new(16, 3),
+            Err(Error::InvalidFormat {
+                exponent_bits: 16,
+                mantissa_bits: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::error::Error;
+use crate::ieee754::format::Format;
+use crate::ieee754::options::Endianness;
+use alloc::vec::Vec;
+
+#[derive(Debug)]
+pub struct HexParser;
+
+impl HexParser {
+    pub const SEPARATORS: [char; 5] = [',', ':', '_', '-', '|'];
+
+    /// Reads the bit pattern of `format` from a hexadecimal string such as
+    /// `C2055555`, `0xc2055555`, `C2 05 55 55` or `0xC2, 0x05, 0x55, 0x55`.
+    /// Whitespace and the characters in [`HexParser::SEPARATORS`] may appear
+    /// between digits and every group may carry its own `0x` prefix.
+    pub fn parse(input: &str, format: Format, endianness: Endianness) -> Result<u64, Error> {
+        parse_digits(input, format, endianness, 16)
+    }
+}
+
+/// Shared reader for hexadecimal and binary bit strings. Digits of `radix`
+/// (2 or 16) are collected while whitespace, separators and per-group
+/// `0x`/`0b` prefixes are skipped.
+pub(crate) fn parse_digits(
+    input: &str,
+    format: Format,
+    endianness: Endianness,
+    radix: u32,
+) -> Result<u64, Error> {
+    let (digit_bits, prefix) = if radix == 16 { (4, 'x') } else { (1, 'b') };
+    let mut digits: Vec<(usize, u64)> = Vec::new();
+    let mut group_start = true;
+    let mut characters = input.char_indices().peekable();
+    while let Some((position, character)) = characters.next() {
+        if character.is_whitespace() || HexParser::SEPARATORS.contains(&character) {
+            group_start = true;
+            continue;
+        }
+        if group_start
+            && character == '0'
+            && characters
+                .peek()
+                .is_some_and(|(_, next)| next.to_ascii_lowercase() == prefix)
+        {
+            characters.next();
+            group_start = false;
+            continue;
+        }
+        group_start = false;
+        match character.to_digit(radix) {
+            Some(digit) => digits.push((position, digit as u64)),
+            None => {
+                return Err(Error::UnexpectedCharacter {
+                    position,
+                    character,
+                })
+            }
+        }
+    }
+    if digits.is_empty() {
+        return Err(Error::EmptyValues);
+    }
+
+    let digits_per_byte = (8 / digit_bits) as usize;
+    let expected = match endianness {
+        Endianness::Big => format.total_bits().div_ceil(digit_bits) as usize,
+        Endianness::Little => format.total_bits().div_ceil(8) as usize * digits_per_byte,
+    };
+    if digits.len() != expected {
+        return Err(Error::InvalidDigitCount {
+            expected,
+            actual: digits.len(),
+        });
+    }
+    let bits: u64 = match endianness {
+        Endianness::Big => digits
+            .iter()
+            .fold(0, |bits, (_, digit)| (bits << digit_bits) | digit),
+        Endianness::Little => digits.chunks(digits_per_byte).rev().fold(0, |bits, byte| {
+            byte.iter()
+                .fold(bits, |bits, (_, digit)| (bits << digit_bits) | digit)
+        }),
+    };
+    if bits & !format.bits_mask() != 0 {
+        // Only possible when the width is not a whole number of digits or
+        // bytes; the surplus bits live in the most significant digits.
+        let (position, _) = match endianness {
+            Endianness::Big => digits[0],
+            Endianness::Little => digits[digits.len() - digits_per_byte],
+        };
+        return Err(Error::UnexpectedCharacter {
+            position,
+            character: input[position..].chars().next().unwrap_or('0'),
+        });
+    }
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        let format = Format::BINARY32;
+        for input in [
+            "C2055555",
+            "c2055555",
+            "0xC2055555",
+            "C2 05 55 55",
+            "0xc2, 0x05, 0x55, 0x55",
+            "c205_5555",
+            "  C2:05:55:55\n",
+        ] {
+            assert_eq!(
+                HexParser::parse(input, format, Endianness::Big).unwrap(),
+                0xC2055555
+            );
+        }
+        assert_eq!(
+            HexParser::parse("55 55 05 C2", format, Endianness::Little).unwrap(),
+            0xC2055555
+        );
+        assert_eq!(
+            HexParser::parse("3c00", Format::BINARY16, Endianness::Big).unwrap(),
+            0x3C00
+        );
+    }
+
+    #[test]
+    fn test_parse_hex_errors() {
+        assert!(matches!(
+            HexParser::parse("C2 05 5G 55", Format::BINARY32, Endianness::Big),
+            Err(Error::UnexpectedCharacter {
+                position: 7,
+                character: 'G'
+            })
+        ));
+        assert!(matches!(
+            HexParser::parse("C2 05 55", Format::BINARY32, Endianness::Big),
+            Err(Error::InvalidDigitCount {
+                expected: 8,
+                actual: 6
+            })
+        ));
+        assert!(matches!(
+            HexParser::parse("C20x55555", Format::BINARY32, Endianness::Big),
+            Err(Error::UnexpectedCharacter {
+                position: 3,
+                character: 'x'
+            })
+        ));
+        assert!(matches!(
+            HexParser::parse(" ", Format::BINARY32, Endianness::Big),
+            Err(Error::EmptyValues)
+        ));
+        assert!(matches!(
+            HexParser::parse("FFF", Format::new(3, 7).unwrap(), Endianness::Big),
+            Err(Error::UnexpectedCharacter {
+                position: 0,
+                character: 'F'
+            })
+        ));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::error::Error;
+use crate::ieee754::decimal::DecimalParser;
+use crate::ieee754::format::Format;
+use crate::ieee754::rounding::RoundingMode;
+use alloc::{format, string::String};
+
+#[derive(Debug)]
+pub struct HexFloat;
+
+impl HexFloat {
+    /// C99 `printf("%a")` style representation, e.g. `-0x1.0aaaaap+5`.
+    /// Subnormals are written as `0x0.<fraction>p<min exponent>` like glibc.
+    pub fn format(format: Format, bits: u64) -> String {
+        let sign = if format.sign(bits) { "-" } else { "" };
+        if format.is_nan(bits) {
+            return format!("{}nan", sign);
+        }
+        if format.is_infinite(bits) {
+            return format!("{}inf", sign);
+        }
+        if format.is_zero(bits) {
+            return format!("{}0x0p+0", sign);
+        }
+        let biased_exponent = format.biased_exponent(bits);
+        let (leading, exponent) = if biased_exponent == 0 {
+            (0, format.min_exponent())
+        } else {
+            (1, biased_exponent as i32 - format.bias())
+        };
+        // Pad the mantissa on the right to a whole number of hex digits.
+        let digit_count = format.mantissa_bits().div_ceil(4);
+        let padding = digit_count * 4 - format.mantissa_bits();
+        let fraction = format.mantissa(bits) << padding;
+        let fraction = format!("{:0width$x}", fraction, width = digit_count as usize);
+        let fraction = fraction.trim_end_matches('0');
+        let exponent_sign = if exponent < 0 { "-" } else { "+" };
+        if fraction.is_empty() {
+            format!("{}0x{}p{}{}", sign, leading, exponent_sign, exponent.abs())
+        } else {
+            format!(
+                "{}0x{}.{}p{}{}",
+                sign,
+                leading,
+                fraction,
+                exponent_sign,
+                exponent.abs()
+            )
+        }
+    }
+
+    /// Parses a hexadecimal floating-point literal such as `0x1.8p3`,
+    /// `-0X.Cp-2`, `inf` or `nan`. Literals carrying more digits than the
+    /// format can hold are rounded with `rounding`.
+    pub fn parse(input: &str, format: Format, rounding: RoundingMode) -> Result<u64, Error> {
+        Self::check_syntax(input)?;
+        DecimalParser::parse(input, format, rounding)
+    }
+
+    /// Like [`HexFloat::parse`] but fails with `Error::InexactValue`
+    /// unless the literal is exactly representable in `format`.
+    pub fn parse_exact(input: &str, format: Format) -> Result<u64, Error> {
+        Self::check_syntax(input)?;
+        let rounded = DecimalParser::parse_rounded(input, format, RoundingMode::default())?;
+        if rounded.inexact {
+            return Err(Error::InexactValue);
+        }
+        Ok(rounded.bits)
+    }
+
+    fn check_syntax(input: &str) -> Result<(), Error> {
+        let start = input.len() - input.trim_start().len();
+        let unsigned = input[start..].trim_start_matches(['+', '-']);
+        let position = input.len() - unsigned.len();
+        let word = unsigned.trim_end().to_ascii_lowercase();
+        if word.starts_with("0x") || ["inf", "infinity", "nan"].contains(&word.as_str()) {
+            return Ok(());
+        }
+        match unsigned.chars().nth(usize::from(unsigned.starts_with('0'))) {
+            Some(character) => Err(Error::UnexpectedCharacter {
+                position: position + usize::from(unsigned.starts_with('0')),
+                character,
+            }),
+            None => Err(Error::UnexpectedEnd {
+                position: input.len(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_hex_float() {
+        assert_eq!(
+            HexFloat::format(Format::BINARY32, 0xC2055555),
+            "-0x1.0aaaaap+5"
+        );
+        assert_eq!(
+            HexFloat::format(Format::BINARY32, 0x3DCCCCCD),
+            "0x1.99999ap-4"
+        );
+        assert_eq!(
+            HexFloat::format(Format::BINARY64, 0.1f64.to_bits()),
+            "0x1.999999999999ap-4"
+        );
+        assert_eq!(
+            HexFloat::format(Format::BINARY64, 1.0f64.to_bits()),
+            "0x1p+0"
+        );
+        assert_eq!(
+            HexFloat::format(Format::BINARY64, 1),
+            "0x0.0000000000001p-1022"
+        );
+        assert_eq!(HexFloat::format(Format::BINARY32, 0x80000000), "-0x0p+0");
+        assert_eq!(HexFloat::format(Format::BINARY16, 0x7BFF), "0x1.ffcp+15");
+        assert_eq!(HexFloat::format(Format::BINARY16, 0xFC00), "-inf");
+        assert_eq!(HexFloat::format(Format::BINARY16, 0x7E00), "nan");
+    }
+
+    #[test]
+    fn test_parse_hex_float() {
+        for format in [
+            Format::BINARY16,
+            Format::BFLOAT16,
+            Format::new(4, 3).unwrap(),
+        ] {
+            for bits in 0..(1u64 << format.total_bits()) {
+                let text = HexFloat::format(format, bits);
+                let parsed = HexFloat::parse_exact(&text, format).unwrap();
+                if format.is_nan(bits) {
+                    assert!(format.is_nan(parsed));
+                } else {
+                    assert_eq!(parsed, bits, "{}", text);
+                }
+            }
+        }
+        assert_eq!(
+            HexFloat::parse("0x1.0aaaaa8p+5", Format::BINARY32, RoundingMode::TowardZero).unwrap(),
+            0x42055555
+        );
+        assert!(matches!(
+            HexFloat::parse_exact("0x1.0aaaaa8p+5", Format::BINARY32),
+            Err(Error::InexactValue)
+        ));
+        assert!(matches!(
+            HexFloat::parse("1.5", Format::BINARY32, RoundingMode::default()),
+            Err(Error::UnexpectedCharacter {
+                position: 0,
+                character: '1'
+            })
+        ));
+        assert!(matches!(
+            HexFloat::parse(" -0y1p3", Format::BINARY32, RoundingMode::default()),
+            Err(Error::UnexpectedCharacter {
+                position: 3,
+                character: 'y'
+            })
+        ));
+    }
+}
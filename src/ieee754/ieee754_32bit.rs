@@ -1,148 +1,104 @@
-use crate::helper::{ComputeMantissaBits, SplitFloat};
-use crate::ieee754::validation::ValidationError;
+use crate::error::Error;
+use crate::helper::PackBits;
+use crate::ieee754::flush::{FlushMode, Flushed};
+use crate::ieee754::format::Format;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[derive(Debug)]
 pub struct IEEE754_32bit {}
 
 impl IEEE754_32bit {
-    pub fn validate(
-        exponent_binaries: &[u8],
-        mantissa_binaries: &[u8],
-    ) -> Result<(), ValidationError> {
+    pub const BIAS: i32 = 127;
+    pub const EXPONENT_MASK: u32 = ((1 << 8) - 1) << 23;
+
+    pub fn validate(exponent_binaries: &[u8], mantissa_binaries: &[u8]) -> Result<(), Error> {
         if exponent_binaries.len() != 8 {
-            return Err(ValidationError::InvalidBitLength);
+            return Err(Error::InvalidBitLength {
+                expected: 8,
+                actual: exponent_binaries.len(),
+            });
         }
         // Infinity Validation
         let all_1s: &[u8; 8] = &[1; 8];
         let all_0s: &[u8; 8] = &[0; 8];
         if exponent_binaries == all_1s {
-            return Err(ValidationError::ExponentAll1s);
+            return Err(Error::ExponentAll1s);
         }
         if mantissa_binaries == all_0s {
-            return Err(ValidationError::MantissaAll0s);
+            return Err(Error::MantissaAll0s);
         }
 
         Ok(())
     }
 
-    pub fn get_exponent(binaries: &[u8]) -> Result<i32, ValidationError> {
-        let value_str: String = binaries
-            .iter()
-            .map(|x| x.to_string())
-            .collect::<Vec<String>>()
-            .concat();
-
-        match u32::from_str_radix(&value_str, 2) {
-            Ok(value) => {
-                if value == 0 {
-                    return Ok(0);
-                }
-                let bias: i32 = 127;
-                let actual_exponent: i32 = value as i32 - bias;
-                Ok(actual_exponent)
-            }
-            Err(_error) => Err(ValidationError::ParseError),
+    pub fn get_exponent(binaries: &[u8]) -> Result<i32, Error> {
+        if binaries.len() != 8 {
+            return Err(Error::InvalidBitLength {
+                expected: 8,
+                actual: binaries.len(),
+            });
         }
+        // Zero and subnormals (biased 0) come back as -BIAS, apart from
+        // values in [1, 2) (biased BIAS) at 0.
+        Ok(PackBits::pack(binaries)? as i32 - Self::BIAS)
     }
 
-    pub fn get_mantissa(binaries: &[u8], exponent: i32) -> Result<f32, ValidationError> {
-        if binaries == [0; 23] && exponent == 0 {
-            return Ok(0.0);
+    pub fn get_mantissa(binaries: &[u8], exponent: i32) -> Result<f32, Error> {
+        if binaries.len() != 23 {
+            return Err(Error::InvalidBitLength {
+                expected: 23,
+                actual: binaries.len(),
+            });
         }
-        match binaries.get(0..exponent as usize) {
-            Some(msb_values) => {
-                let mut msb_values: Vec<u8> = msb_values.to_vec();
-                msb_values.insert(0, 1);
-                let msb_value_str = msb_values
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<String>>()
-                    .concat();
-                match i32::from_str_radix(&msb_value_str, 2) {
-                    Ok(msb_value) => match binaries.get(exponent as usize..) {
-                        Some(lsb_values) => {
-                            let mut lsb_value: f32 = 0.0;
-                            for (index, value) in lsb_values.iter().enumerate() {
-                                lsb_value +=
-                                    value.to_owned() as f32 * 2f32.powi(-(index as i32 + 1));
-                            }
-                            Ok(msb_value as f32 + lsb_value)
-                        }
-                        None => Err(ValidationError::InvalidLSBMantissa),
-                    },
-                    Err(_) => Err(ValidationError::InvalidMSBMantissa),
-                }
-            }
-            None => Err(ValidationError::EmptyMantissa),
+        let mantissa = PackBits::pack(binaries)? as u32;
+        let biased_exponent = exponent + Self::BIAS;
+        if !(0..=2 * Self::BIAS).contains(&biased_exponent) {
+            return Err(Error::InvalidExponent);
         }
+        Ok(f32::from_bits(((biased_exponent as u32) << 23) | mantissa))
     }
 
-    pub fn get_binary(value: f32) -> Result<Vec<u8>, String> {
-        let split_float: (u8, u32, f32) = SplitFloat::f32(value)?;
-
-        let sign_bit: u8 = split_float.0;
-
-        // Integer Part
-        let mut integer_part: u32 = split_float.1;
-        let mut integer_part_bin: Vec<u8> = Vec::new();
-        loop {
-            if integer_part == 0 {
-                break;
-            }
-            let remainder: u8 = (integer_part % 2) as u8;
-            integer_part_bin.push(remainder);
-            integer_part = integer_part / 2;
+    /// Allocation free decoding of a raw bit pattern. Like `validate`, an
+    /// all 1s exponent (infinity or NaN) is rejected; `Format::nan_payload`
+    /// reads NaN patterns.
+    pub const fn decode(bits: u32) -> Result<f32, Error> {
+        if bits & Self::EXPONENT_MASK == Self::EXPONENT_MASK {
+            return Err(Error::ExponentAll1s);
         }
+        Ok(f32::from_bits(bits))
+    }
 
-        // Fractional Part
-        let mut fractional_part: f32 = split_float.2;
-        let mut fractional_part_bin: Vec<u8> = Vec::new();
-        loop {
-            if fractional_part == 0.0 || fractional_part_bin.len() > 23 {
-                break;
-            }
-            fractional_part = fractional_part * 2.0;
-            let front_number: u8 = fractional_part as u8;
-            fractional_part_bin.push(front_number);
-            fractional_part = SplitFloat::f32(fractional_part)?.2;
-        }
-
-        let bias: i32 = 127;
-        let mut exponent: i32 = 0;
-
-        if integer_part_bin.len() > 0 {
-            exponent = (integer_part_bin.len() - 1) as i32;
-            exponent = exponent + bias;
-        }
+    pub const fn encode(value: f32) -> u32 {
+        value.to_bits()
+    }
 
-        let mut exponent_bin: Vec<u8> = Vec::new();
-        loop {
-            if exponent == 0 || exponent_bin.len() == 8 {
-                break;
-            }
-            let remainder: u8 = (exponent % 2) as u8;
-            exponent_bin.push(remainder);
-            exponent = exponent / 2;
-        }
-        if exponent_bin.len() > 0 {
-            exponent_bin.reverse();
-        }
-        if integer_part_bin.len() > 0 {
-            integer_part_bin.reverse();
-            integer_part_bin.remove(0);
-        }
+    /// `decode` that reads subnormals as zero when `mode` has
+    /// denormals-are-zero enabled.
+    pub fn decode_with(bits: u32, mode: FlushMode) -> Result<Flushed<f32>, Error> {
+        let input = mode.input(Format::BINARY32, bits as u64);
+        Self::decode(input.value as u32).map(|value| Flushed {
+            value,
+            flushed: input.flushed,
+        })
+    }
 
-        let mut mantissa_bin: Vec<u8> = Vec::new();
-        mantissa_bin.append(&mut integer_part_bin);
-        mantissa_bin.append(&mut fractional_part_bin);
-        if mantissa_bin.len() > 23 {
-            mantissa_bin = ComputeMantissaBits::compute(mantissa_bin, 23usize)?;
+    /// `encode` that writes subnormals as zero when `mode` has
+    /// flush-to-zero enabled.
+    pub const fn encode_with(value: f32, mode: FlushMode) -> Flushed<u32> {
+        let output = mode.output(Format::BINARY32, Self::encode(value) as u64);
+        Flushed {
+            value: output.value as u32,
+            flushed: output.flushed,
         }
-        let mut binary: Vec<u8> = Vec::new();
-        binary.push(sign_bit);
-        binary.append(&mut exponent_bin);
-        binary.append(&mut mantissa_bin);
+    }
 
-        binary.resize(32, 0);
-        Ok(binary)
+    /// The 32 bits of `value`, sign bit first.
+    #[cfg(feature = "alloc")]
+    pub fn get_binary(value: f32) -> Result<Vec<u8>, Error> {
+        let bits = Self::encode(value);
+        Ok((0..32)
+            .rev()
+            .map(|index| ((bits >> index) & 1) as u8)
+            .collect())
     }
 }
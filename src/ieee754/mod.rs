@@ -1,8 +1,64 @@
+pub mod arithmetic;
+#[cfg(feature = "alloc")]
+pub mod batch;
+#[cfg(feature = "alloc")]
+mod bignum;
+#[cfg(feature = "alloc")]
+pub mod binary;
+pub mod convert;
+#[cfg(feature = "alloc")]
+pub mod decimal;
+#[cfg(feature = "alloc")]
+pub mod diagram;
+#[cfg(feature = "alloc")]
+pub mod explain;
+pub mod flush;
+pub mod format;
+#[cfg(feature = "alloc")]
+pub mod hex;
+#[cfg(feature = "alloc")]
+pub mod hexfloat;
 pub mod ieee754_32bit;
 pub mod ieee754_64bit;
+pub mod nan;
+pub mod options;
+pub mod order;
+#[cfg(feature = "alloc")]
+pub mod precision;
+pub mod rounding;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod ulp;
 pub mod validation;
 
+pub use arithmetic::{Flags, Outcome};
+#[cfg(feature = "alloc")]
+pub use batch::{Batch, BatchReport, ElementError};
+#[cfg(feature = "alloc")]
+pub use binary::BinaryParser;
+pub use convert::{ConvertOptions, NanConversion, Overflow, Subnormals};
+#[cfg(feature = "alloc")]
+pub use decimal::{DecimalFormatter, DecimalParser};
+#[cfg(feature = "alloc")]
+pub use diagram::Diagram;
+#[cfg(feature = "alloc")]
+pub use explain::{Explainer, Step, Trace};
+pub use flush::{FlushMode, Flushed};
+pub use format::{Class, Format};
+#[cfg(feature = "alloc")]
+pub use hex::HexParser;
+#[cfg(feature = "alloc")]
+pub use hexfloat::HexFloat;
 pub use ieee754_32bit::IEEE754_32bit;
 pub use ieee754_64bit::IEEE754_64bit;
+#[cfg(feature = "alloc")]
+pub use options::FormatOptions;
+pub use options::{Endianness, Grouping};
+#[cfg(feature = "alloc")]
+pub use precision::PrecisionReport;
+pub use rounding::RoundingMode;
+#[cfg(feature = "std")]
+pub use stream::{FloatReader, FloatWriter};
 pub use validation::ValidationError;
-
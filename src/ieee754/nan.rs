@@ -0,0 +1,118 @@
+use crate::error::Error;
+use crate::ieee754::format::Format;
+
+/// NaN construction and inspection, e.g. for missing-data markers carried
+/// in the payload. The payload is the mantissa without its leading bit,
+/// which tells quiet (1) from signaling (0) NaNs. These work on raw bit
+/// patterns, including the ones `decode` and `validate` reject.
+impl Format {
+    /// Number of payload bits, one less than the mantissa bits. Formats
+    /// without infinities have a single NaN and no payload.
+    pub const fn payload_bits(&self) -> u32 {
+        if self.has_infinities() {
+            self.mantissa_bits() - 1
+        } else {
+            0
+        }
+    }
+
+    pub const fn quiet_nan_with(&self, sign: bool, payload: u64) -> Result<u64, Error> {
+        self.nan_with(sign, true, payload)
+    }
+
+    /// Signaling NaNs need a non-zero payload, an all zero mantissa would
+    /// be infinity.
+    pub const fn signaling_nan_with(&self, sign: bool, payload: u64) -> Result<u64, Error> {
+        self.nan_with(sign, false, payload)
+    }
+
+    pub const fn nan_with(&self, sign: bool, quiet: bool, payload: u64) -> Result<u64, Error> {
+        let quiet_bit = 1 << self.payload_bits();
+        if payload >= quiet_bit || (!quiet && payload == 0) {
+            return Err(Error::InvalidPayload {
+                payload,
+                bits: self.payload_bits(),
+            });
+        }
+        if !self.has_infinities() {
+            return Ok(self.infinity(sign));
+        }
+        let mantissa = if quiet { quiet_bit | payload } else { payload };
+        Ok(self.compose(sign, self.max_biased_exponent(), mantissa))
+    }
+
+    /// Payload of a NaN, `None` for any other value.
+    pub const fn nan_payload(&self, bits: u64) -> Option<u64> {
+        if self.is_nan(bits) {
+            Some(self.mantissa(bits) & ((1 << self.payload_bits()) - 1))
+        } else {
+            None
+        }
+    }
+
+    pub const fn is_quiet_nan(&self, bits: u64) -> bool {
+        self.is_nan(bits) && !self.is_signaling_nan(bits)
+    }
+
+    /// Replaces any NaN with the default quiet NaN (positive, empty payload)
+    /// so NaNs compare equal bit for bit. Other values are unchanged.
+    pub const fn canonicalize_nan(&self, bits: u64) -> u64 {
+        if self.is_nan(bits) {
+            self.quiet_nan()
+        } else {
+            bits
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nan_payloads() {
+        let double = Format::BINARY64;
+        let marker = double.quiet_nan_with(false, 0x1234).unwrap();
+        assert_eq!(marker, 0x7FF8000000001234);
+        assert!(f64::from_bits(marker).is_nan());
+        assert_eq!(double.nan_payload(marker), Some(0x1234));
+        assert!(double.is_quiet_nan(marker));
+        assert_eq!(double.canonicalize_nan(marker), 0x7FF8000000000000);
+        assert_eq!(
+            double.canonicalize_nan(0x3FF0000000000000),
+            0x3FF0000000000000
+        );
+        assert_eq!(double.nan_payload(0x7FF0000000000000), None);
+
+        let fp8 = Format::E4M3FN;
+        assert_eq!(fp8.payload_bits(), 0);
+        assert_eq!(fp8.quiet_nan_with(true, 0), Ok(0xFF));
+        assert!(fp8.quiet_nan_with(false, 1).is_err());
+        assert!(fp8.signaling_nan_with(false, 0).is_err());
+        assert_eq!(fp8.nan_payload(0x7F), Some(0));
+        assert!(fp8.is_quiet_nan(0xFF));
+
+        let half = Format::BINARY16;
+        assert_eq!(half.payload_bits(), 9);
+        assert_eq!(half.signaling_nan_with(true, 1), Ok(0xFC01));
+        assert!(half.is_signaling_nan(0xFC01));
+        assert!(!half.is_quiet_nan(0xFC01));
+        assert_eq!(half.nan_payload(0xFC01), Some(1));
+        assert_eq!(half.quiet_nan_with(false, 0x1FF), Ok(0x7FFF));
+        assert_eq!(
+            half.quiet_nan_with(false, 0x200),
+            Err(Error::InvalidPayload {
+                payload: 0x200,
+                bits: 9
+            })
+        );
+        assert_eq!(
+            half.signaling_nan_with(false, 0),
+            Err(Error::InvalidPayload {
+                payload: 0,
+                bits: 9
+            })
+        );
+        assert_eq!(Format::BFLOAT16.quiet_nan_with(true, 0x3F), Ok(0xFFFF));
+    }
+}
@@ -0,0 +1,317 @@
+#[cfg(feature = "alloc")]
+use crate::ieee754::format::Format;
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endianness {
+    #[default]
+    Big,
+    Little,
+}
+
+impl Endianness {
+    /// Reads up to eight bytes as an unsigned integer.
+    pub fn read(&self, bytes: &[u8]) -> u64 {
+        match self {
+            Endianness::Big => bytes
+                .iter()
+                .fold(0, |bits, byte| (bits << 8) | *byte as u64),
+            Endianness::Little => bytes
+                .iter()
+                .rev()
+                .fold(0, |bits, byte| (bits << 8) | *byte as u64),
+        }
+    }
+
+    /// Writes the low `output.len()` bytes of `bits`.
+    pub fn write(&self, bits: u64, output: &mut [u8]) {
+        let length = output.len();
+        for (i, byte) in output.iter_mut().enumerate() {
+            let shift = match self {
+                Endianness::Big => 8 * (length - 1 - i),
+                Endianness::Little => 8 * i,
+            };
+            *byte = (bits >> shift) as u8;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Grouping {
+    #[default]
+    None,
+    Nibble,
+    Byte,
+    /// Groups of the given number of bits, e.g. `Bits(16)` for `c205_5555`.
+    /// Hexadecimal output groups whole digits, so the size is rounded up to
+    /// a multiple of 4: `Bits(6)` groups two digits, like `Byte`.
+    Bits(u32),
+    /// Sign, exponent and mantissa fields, e.g. `1 10000100 0000101...`.
+    /// Only applies to binary output, always most significant bit first;
+    /// hexadecimal output is not grouped, like `None`.
+    Fields,
+}
+
+#[cfg(feature = "alloc")]
+impl Grouping {
+    fn bits(&self) -> Option<u32> {
+        match self {
+            Grouping::None => None,
+            Grouping::Nibble => Some(4),
+            Grouping::Byte => Some(8),
+            Grouping::Bits(0) => None,
+            Grouping::Bits(bits) => Some(*bits),
+            Grouping::Fields => None,
+        }
+    }
+}
+
+/// Output options for hexadecimal and binary renderings of a bit pattern.
+/// The defaults reproduce `IEEE754::to_32bit_hex`: uppercase, no prefix and
+/// no grouping, most significant byte first.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    uppercase: bool,
+    prefix: bool,
+    grouping: Grouping,
+    separator: String,
+    endianness: Endianness,
+}
+
+#[cfg(feature = "alloc")]
+impl Default for FormatOptions {
+    fn default() -> FormatOptions {
+        FormatOptions {
+            uppercase: true,
+            prefix: false,
+            grouping: Grouping::None,
+            separator: " ".to_string(),
+            endianness: Endianness::Big,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FormatOptions {
+    pub fn new() -> FormatOptions {
+        FormatOptions::default()
+    }
+
+    pub fn uppercase(mut self, uppercase: bool) -> FormatOptions {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Prepends `0x` to hexadecimal and `0b` to binary output.
+    pub fn prefix(mut self, prefix: bool) -> FormatOptions {
+        self.prefix = prefix;
+        self
+    }
+
+    pub fn grouping(mut self, grouping: Grouping) -> FormatOptions {
+        self.grouping = grouping;
+        self
+    }
+
+    pub fn separator(mut self, separator: &str) -> FormatOptions {
+        self.separator = separator.to_string();
+        self
+    }
+
+    pub fn endianness(mut self, endianness: Endianness) -> FormatOptions {
+        self.endianness = endianness;
+        self
+    }
+
+    pub fn format_hex(&self, format: Format, bits: u64) -> String {
+        let digits: String = match self.endianness {
+            Endianness::Big => format!(
+                "{:0width$x}",
+                bits,
+                width = format.total_bits().div_ceil(4) as usize
+            ),
+            Endianness::Little => Self::little_endian_bytes(format, bits)
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect(),
+        };
+        let digits = if self.uppercase {
+            digits.to_uppercase()
+        } else {
+            digits
+        };
+        let group = self.grouping.bits().map(|bits| bits.div_ceil(4) as usize);
+        self.join(&digits, group, "0x")
+    }
+
+    pub fn format_binary(&self, format: Format, bits: u64) -> String {
+        if self.grouping == Grouping::Fields {
+            let mut output = String::new();
+            if self.prefix {
+                output.push_str("0b");
+            }
+            output.push(if format.sign(bits) { '1' } else { '0' });
+            output.push_str(&self.separator);
+            output.push_str(&format!(
+                "{:0width$b}",
+                format.biased_exponent(bits),
+                width = format.exponent_bits() as usize
+            ));
+            output.push_str(&self.separator);
+            output.push_str(&format!(
+                "{:0width$b}",
+                format.mantissa(bits),
+                width = format.mantissa_bits() as usize
+            ));
+            return output;
+        }
+        let digits: String = match self.endianness {
+            Endianness::Big => format!("{:0width$b}", bits, width = format.total_bits() as usize),
+            Endianness::Little => Self::little_endian_bytes(format, bits)
+                .iter()
+                .map(|byte| format!("{:08b}", byte))
+                .collect(),
+        };
+        self.join(
+            &digits,
+            self.grouping.bits().map(|bits| bits as usize),
+            "0b",
+        )
+    }
+
+    fn little_endian_bytes(format: Format, bits: u64) -> Vec<u8> {
+        let count = format.total_bits().div_ceil(8) as usize;
+        bits.to_le_bytes()[..count].to_vec()
+    }
+
+    /// Splits `digits` into groups counted from the least significant end so
+    /// a short leading group stays in front, as in `1 10000100 ...`.
+    fn join(&self, digits: &str, group: Option<usize>, prefix: &str) -> String {
+        let mut output = String::new();
+        if self.prefix {
+            output.push_str(prefix);
+        }
+        match group {
+            Some(size) if size < digits.len() => {
+                let leading = match digits.len() % size {
+                    0 => size,
+                    remainder => remainder,
+                };
+                output.push_str(&digits[..leading]);
+                let mut start = leading;
+                while start < digits.len() {
+                    output.push_str(&self.separator);
+                    output.push_str(&digits[start..start + size]);
+                    start += size;
+                }
+            }
+            _ => output.push_str(digits),
+        }
+        output
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_hex_options() {
+        let bits = 0xC2055555;
+        let format = Format::BINARY32;
+        assert_eq!(FormatOptions::new().format_hex(format, bits), "C2055555");
+        assert_eq!(
+            FormatOptions::new()
+                .uppercase(false)
+                .prefix(true)
+                .format_hex(format, bits),
+            "0xc2055555"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .grouping(Grouping::Byte)
+                .format_hex(format, bits),
+            "C2 05 55 55"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .uppercase(false)
+                .grouping(Grouping::Bits(16))
+                .separator("_")
+                .format_hex(format, bits),
+            "c205_5555"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .grouping(Grouping::Bits(6))
+                .format_hex(format, bits),
+            "C2 05 55 55"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .prefix(true)
+                .grouping(Grouping::Byte)
+                .separator(", ")
+                .endianness(Endianness::Little)
+                .format_hex(format, bits),
+            "0x55, 55, 05, C2"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .grouping(Grouping::Nibble)
+                .separator(":")
+                .format_hex(Format::BINARY16, 0x3C00),
+            "3:C:0:0"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .grouping(Grouping::Byte)
+                .format_hex(Format::new(3, 8).unwrap(), 0xABC),
+            "A BC"
+        );
+    }
+
+    #[test]
+    fn test_format_binary_options() {
+        let bits = 0xC2055555;
+        let format = Format::BINARY32;
+        assert_eq!(
+            FormatOptions::new().format_binary(format, bits),
+            "11000010000001010101010101010101"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .prefix(true)
+                .grouping(Grouping::Byte)
+                .separator("_")
+                .format_binary(format, bits),
+            "0b11000010_00000101_01010101_01010101"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .grouping(Grouping::Nibble)
+                .endianness(Endianness::Little)
+                .format_binary(Format::BINARY16, 0x3C01),
+            "0000 0001 0011 1100"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .grouping(Grouping::Fields)
+                .format_binary(format, bits),
+            "1 10000100 00001010101010101010101"
+        );
+        assert_eq!(
+            FormatOptions::new()
+                .grouping(Grouping::Fields)
+                .separator("|")
+                .format_hex(Format::BINARY16, 0x3C00),
+            "3C00"
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use core::cmp::Ordering;
+
+use crate::ieee754::format::Format;
+
+impl Format {
+    /// Unsigned key whose natural order is the IEEE 754 `totalOrder` of the
+    /// encodings: `-NaN < -inf < ... < -0 < +0 < ... < +inf < +NaN`. NaNs of
+    /// one sign are ordered by their mantissa, so signaling NaNs sort closer
+    /// to the infinities than quiet ones. Equal keys mean equal bits.
+    pub const fn sort_key(&self, bits: u64) -> u64 {
+        let bits = bits & self.bits_mask();
+        if self.sign(bits) {
+            !bits & self.bits_mask()
+        } else {
+            bits | self.sign_mask()
+        }
+    }
+
+    /// IEEE 754 `totalOrder(a, b)`: whether `a` is ordered before or equal
+    /// to `b`. Unlike `<=` it is total, so it sorts NaNs and tells `-0`
+    /// from `+0`.
+    pub const fn total_order(&self, a: u64, b: u64) -> bool {
+        self.sort_key(a) <= self.sort_key(b)
+    }
+
+    /// IEEE 754 `totalOrderMag(a, b)`, `totalOrder` of the absolute values.
+    pub const fn total_order_mag(&self, a: u64, b: u64) -> bool {
+        self.total_order(a & !self.sign_mask(), b & !self.sign_mask())
+    }
+
+    /// `totalOrder` as an [`Ordering`], for `sort_by`.
+    pub const fn total_cmp(&self, a: u64, b: u64) -> Ordering {
+        let (a, b) = (self.sort_key(a), self.sort_key(b));
+        if a < b {
+            Ordering::Less
+        } else if a > b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754::testing::Xorshift;
+
+    #[test]
+    fn test_total_order() {
+        let half = Format::BINARY16;
+        // -qNaN, -sNaN, -inf, -1, -min subnormal, -0, +0, min subnormal, 1,
+        // +inf, +sNaN, +qNaN
+        let ordered = [
+            0xFE00, 0xFC01, 0xFC00, 0xBC00, 0x8001, 0x8000, 0x0000, 0x0001, 0x3C00, 0x7C00, 0x7C01,
+            0x7E00,
+        ];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(half.total_order(*a, *b), i <= j, "{:04X} {:04X}", a, b);
+                assert_eq!(half.total_cmp(*a, *b), i.cmp(&j));
+            }
+        }
+        assert!(half.total_order_mag(0x8000, 0x0000));
+        assert!(half.total_order_mag(0x0000, 0x8000));
+        assert!(half.total_order_mag(0x3C00, 0xC000));
+        assert!(!half.total_order_mag(0xFE00, 0x7C00));
+
+        let mut random = Xorshift::new(0x243F6A8885A308D3);
+        for _ in 0..1000 {
+            let state = random.next_u64();
+            let (a, b) = (state as u32, (state >> 32) as u32);
+            assert_eq!(
+                Format::BINARY32.total_cmp(a as u64, b as u64),
+                f32::from_bits(a).total_cmp(&f32::from_bits(b))
+            );
+            let (a, b) = (state, state.rotate_left(7));
+            assert_eq!(
+                Format::BINARY64.total_cmp(a, b),
+                f64::from_bits(a).total_cmp(&f64::from_bits(b))
+            );
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use crate::error::Error;
+use crate::ieee754::bignum::BigUint;
+use crate::ieee754::decimal::{
+    decimal_digits, magnitude_range, pack, split_decimal, DecimalFormatter, DecimalParser,
+};
+use crate::ieee754::format::Format;
+use crate::ieee754::rounding::RoundingMode;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::cmp::Ordering;
+
+/// How much of a decimal input survives being stored in a format: the
+/// representable neighbours around it, the one `rounding` picks and the
+/// error of that choice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrecisionReport {
+    pub input: String,
+    pub format: Format,
+    pub rounding: RoundingMode,
+    /// The stored encoding.
+    pub bits: u64,
+    /// Greatest representable value at or below the input.
+    pub below: u64,
+    /// Least representable value at or above the input.
+    pub above: u64,
+    pub exact: bool,
+    /// `|stored - input|` as an exact decimal.
+    pub absolute_error: Option<String>,
+    /// `|stored - input| / |input|`, 0 for a zero input.
+    pub relative_error: Option<f64>,
+    /// `(stored - input) / ulp(stored)`, positive when the stored value is
+    /// greater than the input.
+    pub ulp_error: Option<f64>,
+}
+
+impl PrecisionReport {
+    /// Analyzes a decimal literal such as `-33.33333333`. The errors are
+    /// `None` when the input is infinite, NaN or beyond the largest finite
+    /// value, or when it is below the square of the smallest subnormal, where
+    /// the exact arithmetic would only grow without telling anything new.
+    pub fn analyze(
+        input: &str,
+        format: Format,
+        rounding: RoundingMode,
+    ) -> Result<PrecisionReport, Error> {
+        let (negative, unsigned) = split_decimal(input)?;
+        let rounded = DecimalParser::parse_rounded(input, format, rounding)?;
+        let bits = rounded.bits;
+        let mut report = PrecisionReport {
+            input: input.trim().to_string(),
+            format,
+            rounding,
+            bits,
+            below: bits,
+            above: bits,
+            exact: !rounded.inexact,
+            absolute_error: None,
+            relative_error: None,
+            ulp_error: None,
+        };
+        if report.exact {
+            if format.is_finite(bits) {
+                report.absolute_error = Some("0".to_string());
+                report.relative_error = Some(0.0);
+                report.ulp_error = Some(0.0);
+            }
+            return Ok(report);
+        }
+
+        let larger = if rounded.overflow {
+            format.is_infinite(bits)
+        } else {
+            let (digits, exponent) = decimal_digits(unsigned);
+            if magnitude_range(format, &digits, exponent) == Ordering::Less {
+                // Far below half of the smallest subnormal, which only a
+                // directed rounding away from zero stores.
+                !format.is_zero(bits)
+            } else {
+                report.measure(digits, exponent, negative)
+            }
+        };
+        // The stored magnitude is larger or smaller than the input's; the
+        // other neighbour lies one step away on the input's side.
+        if larger != negative {
+            report.below = format.next_down(bits);
+        } else {
+            report.above = format.next_up(bits);
+        }
+        Ok(report)
+    }
+
+    /// Fills in the errors of an inexact finite result and returns whether
+    /// the stored magnitude is larger than the input's.
+    fn measure(&mut self, digits: BigUint, exponent: i64, negative: bool) -> bool {
+        // Bring the input digits * 10^exponent and the stored significand *
+        // 2^stored_exponent over the common denominator 10^a * 2^b.
+        let format = self.format;
+        let (_, significand, stored_exponent) = format.decompose(self.bits);
+        let a = (-exponent).max(0) as u32;
+        let b = (-stored_exponent).max(0) as u32;
+        let mut input = digits;
+        input.mul_pow(10, (exponent + a as i64) as u32);
+        input.shl(b);
+        let mut stored = BigUint::from_u64(significand);
+        stored.shl((stored_exponent + b as i32) as u32);
+        stored.mul_pow(10, a);
+        let larger = stored > input;
+        let mut difference = if larger {
+            stored.clone()
+        } else {
+            input.clone()
+        };
+        difference.sub(if larger { &input } else { &stored });
+
+        // difference / (10^a * 2^b) == difference * 5^b / 10^(a + b)
+        let mut absolute = difference.clone();
+        absolute.mul_pow(5, b);
+        self.absolute_error = Some(DecimalFormatter::fixed_point(&absolute, (a + b) as usize));
+        self.relative_error = Some(ratio(&difference, &input));
+
+        let (_, ulp_significand, ulp_exponent) = format.decompose(format.ulp(self.bits));
+        let mut numerator = difference;
+        let mut denominator = BigUint::from_u64(ulp_significand);
+        denominator.mul_pow(10, a);
+        let shift = b as i32 + ulp_exponent;
+        if shift >= 0 {
+            denominator.shl(shift as u32);
+        } else {
+            numerator.shl((-shift) as u32);
+        }
+        let ulps = ratio(&numerator, &denominator);
+        // The magnitudes compare the other way round for negative inputs.
+        self.ulp_error = Some(if larger != negative { ulps } else { -ulps });
+        larger
+    }
+
+    pub fn to_text(&self) -> String {
+        let value = |bits: u64| {
+            format!(
+                "{} (0x{:0digits$X})",
+                DecimalFormatter::exact(self.format, bits),
+                bits,
+                digits = self.format.total_bits().div_ceil(4) as usize
+            )
+        };
+        let unknown = || "n/a".to_string();
+        let mut output = format!("Precision of {} as {}\n", self.input, self.format);
+        output.push_str(&format!("stored:          {}\n", value(self.bits)));
+        output.push_str(&format!("below:           {}\n", value(self.below)));
+        output.push_str(&format!("above:           {}\n", value(self.above)));
+        output.push_str(&format!(
+            "exact:           {}\n",
+            if self.exact { "yes" } else { "no" }
+        ));
+        output.push_str(&format!(
+            "absolute error:  {}\n",
+            self.absolute_error.clone().unwrap_or_else(unknown)
+        ));
+        output.push_str(&format!(
+            "relative error:  {}\n",
+            self.relative_error
+                .map_or_else(unknown, |error| format!("{:.3e}", error))
+        ));
+        output.push_str(&format!(
+            "ulp error:       {}\n",
+            self.ulp_error
+                .map_or_else(unknown, |error| format!("{:+.3}", error))
+        ));
+        output
+    }
+}
+
+/// `numerator / denominator` rounded to the nearest binary64 value.
+fn ratio(numerator: &BigUint, denominator: &BigUint) -> f64 {
+    if numerator.is_zero() {
+        return 0.0;
+    }
+    let shift = (Format::BINARY64.precision() as i64 + 3 + denominator.bit_len() as i64
+        - numerator.bit_len() as i64)
+        .max(0);
+    let mut scaled = numerator.clone();
+    scaled.shl(shift as u32);
+    let (quotient, remainder) = scaled.div_rem(denominator);
+    let rounded = pack(
+        Format::BINARY64,
+        false,
+        quotient,
+        -shift,
+        !remainder.is_zero(),
+        RoundingMode::NearestTiesToEven,
+    );
+    f64::from_bits(rounded.bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze() {
+        let report =
+            PrecisionReport::analyze("-33.33333333", Format::BINARY32, RoundingMode::default())
+                .unwrap();
+        let single: f32 = "-33.33333333".parse().unwrap();
+        assert_eq!(report.bits, single.to_bits() as u64);
+        assert_eq!(report.bits, 0xC2055555);
+        assert_eq!(report.below, 0xC2055556);
+        assert_eq!(report.above, 0xC2055555);
+        assert!(!report.exact);
+        assert_eq!(
+            report.absolute_error.as_deref(),
+            Some("0.000001268232421875")
+        );
+        let relative = report.relative_error.unwrap();
+        assert!((relative - 0.000001268232421875 / 33.33333333).abs() < 1e-20);
+        assert_eq!(report.ulp_error, Some(0.000001268232421875 * 262144.0));
+        assert_eq!(
+            report.to_text(),
+            "Precision of -33.33333333 as binary32
+stored:          -33.333332061767578125 (0xC2055555)
+below:           -33.33333587646484375 (0xC2055556)
+above:           -33.333332061767578125 (0xC2055555)
+exact:           no
+absolute error:  0.000001268232421875
+relative error:  3.805e-8
+ulp error:       +0.332
+"
+        );
+
+        let double =
+            PrecisionReport::analyze("-33.33333333", Format::BINARY64, RoundingMode::default())
+                .unwrap();
+        assert_eq!(double.bits, (-33.33333333f64).to_bits());
+        assert_eq!(
+            double.absolute_error.as_deref(),
+            Some("0.0000000000000020926745492033660411834716796875")
+        );
+        assert!(double.relative_error.unwrap() < 1e-16);
+        let ulps = double.ulp_error.unwrap();
+        assert!(ulps < 0.0 && ulps > -0.5);
+    }
+
+    #[test]
+    fn test_analyze_exact_and_special() {
+        let half = Format::BINARY16;
+        let report = PrecisionReport::analyze("0.5", half, RoundingMode::default()).unwrap();
+        assert!(report.exact);
+        assert_eq!(
+            (report.below, report.bits, report.above),
+            (0x3800, 0x3800, 0x3800)
+        );
+        assert_eq!(report.absolute_error.as_deref(), Some("0"));
+        assert_eq!(report.relative_error, Some(0.0));
+        assert_eq!(report.ulp_error, Some(0.0));
+
+        let report = PrecisionReport::analyze("-0", half, RoundingMode::default()).unwrap();
+        assert_eq!(report.bits, 0x8000);
+        assert_eq!(report.relative_error, Some(0.0));
+
+        let report = PrecisionReport::analyze("1e6", half, RoundingMode::default()).unwrap();
+        assert_eq!(
+            (report.below, report.bits, report.above),
+            (0x7BFF, 0x7C00, 0x7C00)
+        );
+        assert_eq!(report.absolute_error, None);
+        assert_eq!(report.ulp_error, None);
+
+        let report = PrecisionReport::analyze("1e-10", half, RoundingMode::TowardPositive).unwrap();
+        assert_eq!((report.below, report.bits), (0x0000, 0x0001));
+        assert!(report.relative_error.unwrap() > 1.0);
+
+        let report = PrecisionReport::analyze("2049", half, RoundingMode::default()).unwrap();
+        assert_eq!(
+            (report.below, report.bits, report.above),
+            (0x6800, 0x6800, 0x6801)
+        );
+        assert_eq!(report.absolute_error.as_deref(), Some("1"));
+        assert_eq!(report.ulp_error, Some(-0.5));
+
+        assert!(
+            PrecisionReport::analyze("1e-100000", half, RoundingMode::default())
+                .unwrap()
+                .absolute_error
+                .is_none()
+        );
+        // The smallest subnormal of e2m1 is 0.5, so 0.3 is measured.
+        let tiny = Format::new(2, 1).unwrap();
+        let report = PrecisionReport::analyze("0.3", tiny, RoundingMode::default()).unwrap();
+        assert_eq!(report.bits, 0x1);
+        assert_eq!(report.absolute_error.as_deref(), Some("0.2"));
+        assert!(
+            PrecisionReport::analyze("nan", half, RoundingMode::default())
+                .unwrap()
+                .relative_error
+                .is_none()
+        );
+        assert!(PrecisionReport::analyze("0x1p3", half, RoundingMode::default()).is_err());
+
+        let report = PrecisionReport::analyze("1e-10", half, RoundingMode::default()).unwrap();
+        assert_eq!(
+            (report.below, report.bits, report.above),
+            (0x0000, 0x0000, 0x0001)
+        );
+        assert_eq!(report.relative_error, Some(1.0));
+        let report = PrecisionReport::analyze("-1e-100", half, RoundingMode::default()).unwrap();
+        assert_eq!(
+            (report.below, report.bits, report.above),
+            (0x8001, 0x8000, 0x8000)
+        );
+        let report =
+            PrecisionReport::analyze("1e-100", half, RoundingMode::TowardPositive).unwrap();
+        assert_eq!(
+            (report.below, report.bits, report.above),
+            (0x0000, 0x0001, 0x0001)
+        );
+        let report = PrecisionReport::analyze("1e6", half, RoundingMode::TowardZero).unwrap();
+        assert_eq!(
+            (report.below, report.bits, report.above),
+            (0x7BFF, 0x7BFF, 0x7C00)
+        );
+    }
+
+    #[test]
+    fn test_analyze_long_inputs() {
+        let half = Format::BINARY16;
+        let input = format!("0.5{}", "0".repeat(5000));
+        let report = PrecisionReport::analyze(&input, half, RoundingMode::default()).unwrap();
+        assert!(report.exact);
+        assert_eq!(report.absolute_error.as_deref(), Some("0"));
+        assert_eq!(report.ulp_error, Some(0.0));
+        let input = format!("0.1{}", "0".repeat(5000));
+        let report = PrecisionReport::analyze(&input, half, RoundingMode::default()).unwrap();
+        assert_eq!(report.absolute_error.as_deref(), Some("0.0000244140625"));
+
+        let wide = Format::new(15, 48).unwrap();
+        let report = PrecisionReport::analyze("1.1e-4500", wide, RoundingMode::default()).unwrap();
+        assert_eq!(report.bits, 0x59A6066CD1E3145);
+        assert!(!wide.is_subnormal(report.bits));
+        assert!(report.relative_error.unwrap() < 2f64.powi(-48));
+        assert!(report.ulp_error.unwrap().abs() <= 0.5);
+        assert!(report.absolute_error.unwrap().len() > 4500);
+    }
+}
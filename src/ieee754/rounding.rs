@@ -0,0 +1,123 @@
+use crate::ieee754::format::Format;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    #[default]
+    NearestTiesToEven,
+    NearestTiesToAway,
+    TowardZero,
+    TowardPositive,
+    TowardNegative,
+}
+
+impl RoundingMode {
+    /// Decides whether a truncated magnitude has to be incremented, given the
+    /// last kept bit, the first dropped (round) bit and whether any other
+    /// dropped bit is set (sticky).
+    pub const fn round_up(&self, sign: bool, lsb: bool, round: bool, sticky: bool) -> bool {
+        match self {
+            RoundingMode::NearestTiesToEven => round && (sticky || lsb),
+            RoundingMode::NearestTiesToAway => round,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !sign && (round || sticky),
+            RoundingMode::TowardNegative => sign && (round || sticky),
+        }
+    }
+
+    /// Whether an overflowing result becomes infinity rather than the
+    /// largest finite value.
+    pub const fn overflows_to_infinity(&self, sign: bool) -> bool {
+        match self {
+            RoundingMode::NearestTiesToEven | RoundingMode::NearestTiesToAway => true,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !sign,
+            RoundingMode::TowardNegative => sign,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Rounded {
+    pub bits: u64,
+    pub inexact: bool,
+    pub overflow: bool,
+    pub underflow: bool,
+}
+
+impl Format {
+    /// Rounds the magnitude `(significand + s) * 2^exponent` to this format,
+    /// where `s` is an unknown fraction in `(0, 1)` when `sticky` is set and
+    /// zero otherwise. A sticky value needs a non-zero significand.
+    pub(crate) const fn round_pack(
+        &self,
+        sign: bool,
+        significand: u128,
+        exponent: i32,
+        sticky: bool,
+        mode: RoundingMode,
+    ) -> Rounded {
+        if significand == 0 {
+            return Rounded {
+                bits: self.zero(sign),
+                inexact: false,
+                overflow: false,
+                underflow: false,
+            };
+        }
+        let mantissa_bits = self.mantissa_bits() as i32;
+        let top = exponent + (127 - significand.leading_zeros() as i32);
+        if top > self.max_exponent() {
+            return self.overflow(sign, mode);
+        }
+        let tiny = top < self.min_exponent();
+        let unbiased = if tiny { self.min_exponent() } else { top };
+        let shift = unbiased - mantissa_bits - exponent;
+
+        let (mut kept, round, rest) = if shift <= 0 {
+            (significand << -shift, false, false)
+        } else if shift > 128 {
+            (0, false, true)
+        } else {
+            let kept = if shift == 128 {
+                0
+            } else {
+                significand >> shift
+            };
+            let round = (significand >> (shift - 1)) & 1 == 1;
+            let rest = significand & ((1u128 << (shift - 1)) - 1) != 0;
+            (kept, round, rest)
+        };
+        let sticky = sticky || rest;
+        let inexact = round || sticky;
+        if mode.round_up(sign, kept & 1 == 1, round, sticky) {
+            kept += 1;
+        }
+
+        let base = (unbiased + self.bias() - 1) as u64;
+        let magnitude = (base << mantissa_bits) + kept as u64;
+        if magnitude >= self.infinity(false) {
+            return self.overflow(sign, mode);
+        }
+        let sign_bit = if sign { self.sign_mask() } else { 0 };
+        Rounded {
+            bits: sign_bit | magnitude,
+            inexact,
+            overflow: false,
+            underflow: tiny && inexact,
+        }
+    }
+
+    const fn overflow(&self, sign: bool, mode: RoundingMode) -> Rounded {
+        let bits = if mode.overflows_to_infinity(sign) {
+            self.infinity(sign)
+        } else {
+            self.max_finite(sign)
+        };
+        Rounded {
+            bits,
+            inexact: true,
+            overflow: true,
+            underflow: false,
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::io::{ErrorKind, Read, Write};
+
+use crate::error::Error;
+use crate::ieee754::batch::Batch;
+use crate::ieee754::format::Format;
+use crate::ieee754::ieee754_32bit::IEEE754_32bit;
+use crate::ieee754::ieee754_64bit::IEEE754_64bit;
+use crate::ieee754::options::Endianness;
+
+/// Lazily reads consecutive encodings of `format` from `reader`, yielding
+/// the raw bit pattern of each. Like `Batch`, an element with bits set
+/// beyond the format is yielded as an error and reading goes on; iteration
+/// only stops after an I/O error or a trailing partial element.
+#[derive(Debug)]
+pub struct FloatReader<R> {
+    reader: R,
+    format: Format,
+    endianness: Endianness,
+    done: bool,
+}
+
+impl<R: Read> FloatReader<R> {
+    pub fn new(reader: R, format: Format, endianness: Endianness) -> FloatReader<R> {
+        FloatReader {
+            reader,
+            format,
+            endianness,
+            done: false,
+        }
+    }
+
+    pub fn decode_32bit(
+        reader: R,
+        endianness: Endianness,
+    ) -> impl Iterator<Item = Result<f32, Error>> {
+        FloatReader::new(reader, Format::BINARY32, endianness)
+            .map(|bits| bits.and_then(|bits| IEEE754_32bit::decode(bits as u32)))
+    }
+
+    pub fn decode_64bit(
+        reader: R,
+        endianness: Endianness,
+    ) -> impl Iterator<Item = Result<f64, Error>> {
+        FloatReader::new(reader, Format::BINARY64, endianness)
+            .map(|bits| bits.and_then(IEEE754_64bit::decode))
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// The underlying reader, e.g. to skip padding between elements.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Fills `buffer` as far as the reader allows and returns the number of
+    /// bytes read, which is only short at end of input.
+    fn fill(&mut self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let mut filled = 0;
+        while filled < buffer.len() {
+            match self.reader.read(&mut buffer[filled..]) {
+                Ok(0) => break,
+                Ok(count) => filled += count,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error.into()),
+            }
+        }
+        Ok(filled)
+    }
+}
+
+impl<R: Read> Iterator for FloatReader<R> {
+    type Item = Result<u64, Error>;
+
+    fn next(&mut self) -> Option<Result<u64, Error>> {
+        if self.done {
+            return None;
+        }
+        let mut buffer = [0u8; 8];
+        let width = Batch::width(self.format);
+        match self.fill(&mut buffer[..width]) {
+            Ok(0) => {
+                self.done = true;
+                None
+            }
+            Ok(count) => {
+                self.done = count < width;
+                Some(Batch::decode_element(
+                    &buffer[..count],
+                    self.format,
+                    self.endianness,
+                ))
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// Writes bit patterns of `format` to `writer` in the chosen byte order.
+#[derive(Debug)]
+pub struct FloatWriter<W> {
+    writer: W,
+    format: Format,
+    endianness: Endianness,
+}
+
+impl<W: Write> FloatWriter<W> {
+    pub fn new(writer: W, format: Format, endianness: Endianness) -> FloatWriter<W> {
+        FloatWriter {
+            writer,
+            format,
+            endianness,
+        }
+    }
+
+    pub fn write_bits(&mut self, bits: u64) -> Result<(), Error> {
+        if bits & !self.format.bits_mask() != 0 {
+            return Err(Error::InvalidBitLength {
+                expected: self.format.total_bits() as usize,
+                actual: 64 - bits.leading_zeros() as usize,
+            });
+        }
+        let mut buffer = [0u8; 8];
+        let width = Batch::width(self.format);
+        self.endianness.write(bits, &mut buffer[..width]);
+        self.writer.write_all(&buffer[..width])?;
+        Ok(())
+    }
+
+    pub fn write_32bit(&mut self, value: f32) -> Result<(), Error> {
+        self.expect(Format::BINARY32)?;
+        self.write_bits(IEEE754_32bit::encode(value) as u64)
+    }
+
+    pub fn write_64bit(&mut self, value: f64) -> Result<(), Error> {
+        self.expect(Format::BINARY64)?;
+        self.write_bits(IEEE754_64bit::encode(value))
+    }
+
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn expect(&self, format: Format) -> Result<(), Error> {
+        if self.format != format {
+            return Err(Error::FormatMismatch {
+                expected: self.format,
+                actual: format,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hands out at most two bytes per call to exercise partial reads.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            let count = buffer.len().min(self.0.len()).min(2);
+            buffer[..count].copy_from_slice(&self.0[..count]);
+            self.0 = &self.0[count..];
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn test_stream_round_trip() {
+        let mut writer = FloatWriter::new(Vec::new(), Format::BINARY32, Endianness::Little);
+        for value in [-2.7182817f32, 0.5, f32::MIN_POSITIVE] {
+            writer.write_32bit(value).unwrap();
+        }
+        writer.write_bits(0x7F800000).unwrap();
+        assert_eq!(
+            writer.write_64bit(1.0),
+            Err(Error::FormatMismatch {
+                expected: Format::BINARY32,
+                actual: Format::BINARY64
+            })
+        );
+        assert_eq!(
+            writer.write_64bit(1.0).unwrap_err().to_string(),
+            "expected binary32 values, found binary64"
+        );
+        let bytes = writer.into_inner();
+        assert_eq!(&bytes[..4], [0x54, 0xf8, 0x2d, 0xc0]);
+
+        let values: Vec<_> =
+            FloatReader::decode_32bit(Trickle(&bytes), Endianness::Little).collect();
+        assert_eq!(
+            values,
+            vec![
+                Ok(-2.7182817),
+                Ok(0.5),
+                Ok(f32::MIN_POSITIVE),
+                Err(Error::ExponentAll1s)
+            ]
+        );
+
+        let mut writer = FloatWriter::new(Vec::new(), Format::BINARY64, Endianness::Big);
+        writer.write_64bit(-85.125).unwrap();
+        let bytes = writer.into_inner();
+        assert_eq!(
+            FloatReader::decode_64bit(bytes.as_slice(), Endianness::Big).collect::<Vec<_>>(),
+            vec![Ok(-85.125)]
+        );
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let bytes = [0x3c, 0x00, 0xc0, 0x00, 0x7c];
+        let mut reader = FloatReader::new(&bytes[..], Format::BINARY16, Endianness::Big);
+        assert_eq!(reader.next(), Some(Ok(0x3C00)));
+        assert_eq!(reader.next(), Some(Ok(0xC000)));
+        assert_eq!(
+            reader.next(),
+            Some(Err(Error::InvalidBitLength {
+                expected: 16,
+                actual: 8
+            }))
+        );
+        assert_eq!(reader.next(), None);
+
+        // 12 bit elements in 2 bytes: stray high bits spoil only their own
+        // element.
+        let format = Format::new(5, 6).unwrap();
+        let bytes = [0xF0, 0x00, 0x01, 0x23];
+        let reader = FloatReader::new(&bytes[..], format, Endianness::Big);
+        assert_eq!(
+            reader.collect::<Vec<_>>(),
+            vec![
+                Err(Error::InvalidBitLength {
+                    expected: 12,
+                    actual: 16
+                }),
+                Ok(0x123)
+            ]
+        );
+
+        let mut writer = FloatWriter::new(Vec::new(), Format::BINARY16, Endianness::Big);
+        assert_eq!(
+            writer.write_bits(0x1_0000),
+            Err(Error::InvalidBitLength {
+                expected: 16,
+                actual: 17
+            })
+        );
+
+        let mut buffer = [0u8; 2];
+        let mut writer = FloatWriter::new(&mut buffer[..], Format::BINARY32, Endianness::Big);
+        assert!(matches!(
+            writer.write_32bit(1.0),
+            Err(Error::Io(error)) if error.kind() == ErrorKind::WriteZero
+        ));
+    }
+}
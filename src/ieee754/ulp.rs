@@ -0,0 +1,184 @@
+use crate::ieee754::format::Format;
+
+impl Format {
+    /// IEEE 754 `nextUp`: the least encoding that compares greater than
+    /// `bits`. `nextUp(-0)` and `nextUp(+0)` are the smallest positive
+    /// subnormal, `+inf` stays `+inf` and a NaN comes back quieted.
+    pub const fn next_up(&self, bits: u64) -> u64 {
+        let bits = bits & self.bits_mask();
+        if self.is_nan(bits) {
+            self.quiet(bits)
+        } else if bits == self.infinity(false) {
+            bits
+        } else if self.is_zero(bits) {
+            1
+        } else if self.sign(bits) {
+            bits - 1
+        } else {
+            bits + 1
+        }
+    }
+
+    /// IEEE 754 `nextDown`, i.e. `-nextUp(-bits)`.
+    pub const fn next_down(&self, bits: u64) -> u64 {
+        let bits = bits & self.bits_mask();
+        if self.is_nan(bits) {
+            return self.quiet(bits);
+        }
+        self.next_up(bits ^ self.sign_mask()) ^ self.sign_mask()
+    }
+
+    /// The neighbour of `from` in the direction of `toward`, or `toward`
+    /// itself when both compare equal (so `next_after(+0, -0)` is `-0`).
+    /// Either operand being NaN gives a quiet NaN.
+    pub const fn next_after(&self, from: u64, toward: u64) -> u64 {
+        if self.is_nan(from) {
+            return self.quiet(from);
+        }
+        if self.is_nan(toward) {
+            return self.quiet(toward);
+        }
+        let (start, end) = (self.ordinal(from), self.ordinal(toward));
+        if start < end {
+            self.next_up(from)
+        } else if start > end {
+            self.next_down(from)
+        } else {
+            toward & self.bits_mask()
+        }
+    }
+
+    /// Unit in the last place of `bits`: the (positive) gap between its
+    /// magnitude and the next larger magnitude. Below a power of two the
+    /// gap is half as wide, so `ulp(1.0)` is `2^-10` in binary16 while
+    /// `1.0 - next_down(1.0)` is `2^-11`. Infinities have an infinite ULP
+    /// and a NaN comes back quieted.
+    pub const fn ulp(&self, bits: u64) -> u64 {
+        if self.is_nan(bits) {
+            return self.quiet(bits);
+        }
+        if self.is_infinite(bits) {
+            return self.infinity(false);
+        }
+        let biased_exponent = self.biased_exponent(bits);
+        let biased_exponent = if biased_exponent == 0 {
+            1
+        } else {
+            biased_exponent
+        };
+        // 2^(exponent - mantissa bits), a subnormal in the lowest binades.
+        if biased_exponent <= self.mantissa_bits() as u64 {
+            1 << (biased_exponent - 1)
+        } else {
+            self.compose(false, biased_exponent - self.mantissa_bits() as u64, 0)
+        }
+    }
+
+    /// Signed number of representable steps from `from` to `toward`:
+    /// positive when `toward` is greater, 0 for equal values (including
+    /// `+0` and `-0`), and `None` when either is NaN. The infinities count
+    /// as one step beyond the largest finite values.
+    pub const fn ulp_distance(&self, from: u64, toward: u64) -> Option<i128> {
+        if self.is_nan(from) || self.is_nan(toward) {
+            return None;
+        }
+        Some(self.ordinal(toward) as i128 - self.ordinal(from) as i128)
+    }
+
+    /// Position of a non-NaN encoding on the number line, with both zeros
+    /// at 0 and consecutive values one apart.
+    pub(crate) const fn ordinal(&self, bits: u64) -> i64 {
+        let magnitude = (bits & self.bits_mask() & !self.sign_mask()) as i64;
+        if self.sign(bits) {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ieee754::testing::Xorshift;
+
+    #[test]
+    fn test_next_up_down() {
+        let half = Format::BINARY16;
+        assert_eq!(half.next_up(0x3C00), 0x3C01);
+        assert_eq!(half.next_down(0x3C00), 0x3BFF);
+        assert_eq!(half.next_up(0x8000), 0x0001);
+        assert_eq!(half.next_up(0x0000), 0x0001);
+        assert_eq!(half.next_down(0x0000), 0x8001);
+        assert_eq!(half.next_up(0x8001), 0x8000);
+        assert_eq!(half.next_up(0x7BFF), 0x7C00);
+        assert_eq!(half.next_up(0x7C00), 0x7C00);
+        assert_eq!(half.next_up(0xFC00), 0xFBFF);
+        assert_eq!(half.next_down(0xFC00), 0xFC00);
+        assert_eq!(half.next_up(0x7D00), 0x7F00);
+        assert_eq!(Format::BFLOAT16.next_up(0x3F80), 0x3F81);
+        assert_eq!(Format::BFLOAT16.next_down(0xBF80), 0xBF81);
+
+        assert_eq!(half.next_after(0x3C00, 0x7C00), 0x3C01);
+        assert_eq!(half.next_after(0x3C00, 0x0000), 0x3BFF);
+        assert_eq!(half.next_after(0x0000, 0x8000), 0x8000);
+        assert_eq!(half.next_after(0x3C00, 0x7E01), 0x7E01);
+
+        let mut random = Xorshift::new(0x243F6A8885A308D3);
+        for _ in 0..1000 {
+            let state = random.next_u64();
+            let single = f32::from_bits(state as u32);
+            let double = f64::from_bits(state);
+            if single.is_nan() || double.is_nan() {
+                continue;
+            }
+            let bits = single.to_bits() as u64;
+            assert_eq!(
+                Format::BINARY32.next_up(bits),
+                single.next_up().to_bits() as u64
+            );
+            assert_eq!(
+                Format::BINARY32.next_down(bits),
+                single.next_down().to_bits() as u64
+            );
+            assert_eq!(Format::BINARY64.next_up(state), double.next_up().to_bits());
+            assert_eq!(
+                Format::BINARY64.next_down(state),
+                double.next_down().to_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn test_ulp() {
+        let half = Format::BINARY16;
+        assert_eq!(half.ulp(0x3C00), 0x1400);
+        assert_eq!(half.ulp(0xBC00), 0x1400);
+        assert_eq!(half.ulp(0x0000), 0x0001);
+        assert_eq!(half.ulp(0x03FF), 0x0001);
+        assert_eq!(half.ulp(0x0400), 0x0001);
+        assert_eq!(half.ulp(0x2800), 0x0200);
+        assert_eq!(half.ulp(0x7BFF), 0x5000);
+        assert_eq!(half.ulp(0xFC00), 0x7C00);
+        assert_eq!(Format::BFLOAT16.ulp(0x3F80), 0x3C00);
+        assert_eq!(
+            Format::BINARY64.ulp(1.0f64.to_bits()),
+            f64::EPSILON.to_bits()
+        );
+        assert_eq!(
+            Format::BINARY32.ulp(f32::MAX.to_bits() as u64),
+            2.0f32.powi(104).to_bits() as u64
+        );
+
+        assert_eq!(half.ulp_distance(0x3C00, 0x3C02), Some(2));
+        assert_eq!(half.ulp_distance(0x3C02, 0x3C00), Some(-2));
+        assert_eq!(half.ulp_distance(0x8000, 0x0000), Some(0));
+        assert_eq!(half.ulp_distance(0x8001, 0x0001), Some(2));
+        assert_eq!(half.ulp_distance(0x7BFF, 0x7C00), Some(1));
+        assert_eq!(half.ulp_distance(0x7E00, 0x3C00), None);
+        assert_eq!(
+            Format::BINARY64.ulp_distance(f64::NEG_INFINITY.to_bits(), f64::INFINITY.to_bits()),
+            Some(2 * 0x7FF0000000000000)
+        );
+    }
+}
@@ -1,16 +1,4 @@
-#[derive(Debug)]
-pub enum ValidationError {
-    ExponentAll1s,
-    MantissaAll0s,
-    InvalidBitLength,
-    ParseError,
-    EmptyValues,
-    InvalidSignBit,
-    InvalidExponent,
-    InvalidMantissa,
-    InvalidMSBMantissa,
-    InvalidLSBMantissa,
-    EmptySignBit,
-    EmptyExponent,
-    EmptyMantissa,
-}
+use crate::error::Error;
+
+/// Name of [`Error`] before it covered the whole crate.
+pub type ValidationError = Error;
@@ -1,125 +1,237 @@
+//! Without the default `std` feature the crate is `#![no_std]`. Bit level
+//! decoding, encoding and classification stay available; parsers and string
+//! producing helpers need the `alloc` feature and streams need `std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod error;
 pub mod helper;
 pub mod ieee754;
 
-use crate::ieee754::{IEEE754_32bit, IEEE754_64bit, ValidationError};
+pub use crate::error::Error;
+#[cfg(feature = "std")]
+pub use crate::error::IoError;
+
+#[cfg(feature = "alloc")]
+use crate::ieee754::{
+    Batch, BinaryParser, DecimalFormatter, DecimalParser, Endianness, Format, FormatOptions,
+    HexFloat, HexParser, RoundingMode,
+};
+use crate::ieee754::{IEEE754_32bit, IEEE754_64bit};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "alloc")]
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
 
 #[derive(Debug)]
 pub struct IEEE754;
 
 impl IEEE754 {
-    pub fn to_hex(binary: Vec<u8>) -> Result<String, String> {
-        let mut hex = String::new();
-        for b in binary.chunks(4) {
-            let byte_str: String = b
-                .into_iter()
-                .map(|v| v.to_string())
-                .collect::<Vec<String>>()
-                .concat();
-
-            match u8::from_str_radix(&byte_str, 2) {
-                Ok(v) => {
-                    hex.push_str(&format!("{:X}", v));
+    pub const fn decode_32bit(bytes: [u8; 4]) -> Result<f32, Error> {
+        IEEE754_32bit::decode(u32::from_be_bytes(bytes))
+    }
+
+    pub const fn decode_64bit(bytes: [u8; 8]) -> Result<f64, Error> {
+        IEEE754_64bit::decode(u64::from_be_bytes(bytes))
+    }
+
+    pub const fn encode_32bit(value: f32) -> [u8; 4] {
+        IEEE754_32bit::encode(value).to_be_bytes()
+    }
+
+    pub const fn encode_64bit(value: f64) -> [u8; 8] {
+        IEEE754_64bit::encode(value).to_be_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl IEEE754 {
+    pub fn to_hex(binary: Vec<u8>) -> Result<String, Error> {
+        let mut hex = String::with_capacity(binary.len().div_ceil(4));
+        for (index, nibble) in binary.chunks(4).enumerate() {
+            let mut value: u32 = 0;
+            for (offset, bit) in nibble.iter().enumerate() {
+                if *bit > 1 {
+                    return Err(Error::InvalidBit {
+                        offset: index * 4 + offset,
+                        value: *bit,
+                    });
                 }
-                Err(error) => return Err(error.to_string()),
+                value = (value << 1) | *bit as u32;
             }
+            hex.push(HEX_DIGITS[value as usize] as char);
         }
         Ok(hex)
     }
 
-    pub fn to_32bit_hex(value: f32) -> Result<String, String> {
-        let binary: Vec<u8> = IEEE754_32bit::get_binary(value)?;
-        let hex: String = IEEE754::to_hex(binary)?;
-        Ok(hex)
+    pub fn to_32bit_hex(value: f32) -> Result<String, Error> {
+        Self::to_32bit_hex_with(value, &FormatOptions::default())
     }
 
-    pub fn to_64bit_hex(value: f64) -> Result<String, String> {
-        let binary: Vec<u8> = IEEE754_64bit::get_binary(value)?;
-        let hex: String = IEEE754::to_hex(binary)?;
-        Ok(hex)
+    pub fn to_64bit_hex(value: f64) -> Result<String, Error> {
+        Self::to_64bit_hex_with(value, &FormatOptions::default())
+    }
+
+    pub fn to_32bit_hex_with(value: f32, options: &FormatOptions) -> Result<String, Error> {
+        let bits = IEEE754_32bit::encode(value);
+        Ok(options.format_hex(Format::BINARY32, bits as u64))
+    }
+
+    pub fn to_64bit_hex_with(value: f64, options: &FormatOptions) -> Result<String, Error> {
+        let bits = IEEE754_64bit::encode(value);
+        Ok(options.format_hex(Format::BINARY64, bits))
     }
 
-    pub fn to_binary(values: Vec<u32>) -> Result<Vec<u8>, ValidationError> {
-        let mut binaries: Vec<u8> = Vec::new();
+    pub fn to_32bit_binary_with(value: f32, options: &FormatOptions) -> Result<String, Error> {
+        let bits = IEEE754_32bit::encode(value);
+        Ok(options.format_binary(Format::BINARY32, bits as u64))
+    }
+
+    pub fn to_64bit_binary_with(value: f64, options: &FormatOptions) -> Result<String, Error> {
+        let bits = IEEE754_64bit::encode(value);
+        Ok(options.format_binary(Format::BINARY64, bits))
+    }
+
+    pub fn from_32bit_hex(input: &str) -> Result<f32, Error> {
+        let bits: u64 = HexParser::parse(input, Format::BINARY32, Endianness::Big)?;
+        Ok(f32::from_bits(bits as u32))
+    }
+
+    pub fn from_64bit_hex(input: &str) -> Result<f64, Error> {
+        let bits: u64 = HexParser::parse(input, Format::BINARY64, Endianness::Big)?;
+        Ok(f64::from_bits(bits))
+    }
+
+    pub fn from_hex(input: &str, format: Format) -> Result<u64, Error> {
+        HexParser::parse(input, format, Endianness::Big)
+    }
+
+    pub fn from_32bit_binary(input: &str) -> Result<f32, Error> {
+        let bits: u64 = BinaryParser::parse(input, Format::BINARY32, Endianness::Big)?;
+        Ok(f32::from_bits(bits as u32))
+    }
+
+    pub fn from_64bit_binary(input: &str) -> Result<f64, Error> {
+        let bits: u64 = BinaryParser::parse(input, Format::BINARY64, Endianness::Big)?;
+        Ok(f64::from_bits(bits))
+    }
+
+    pub fn from_binary(input: &str, format: Format) -> Result<u64, Error> {
+        BinaryParser::parse(input, format, Endianness::Big)
+    }
+
+    pub fn to_32bit_hex_float(value: f32) -> String {
+        HexFloat::format(Format::BINARY32, value.to_bits() as u64)
+    }
+
+    pub fn to_64bit_hex_float(value: f64) -> String {
+        HexFloat::format(Format::BINARY64, value.to_bits())
+    }
+
+    pub fn from_32bit_hex_float(input: &str) -> Result<f32, Error> {
+        let bits: u64 = HexFloat::parse_exact(input, Format::BINARY32)?;
+        Ok(f32::from_bits(bits as u32))
+    }
+
+    pub fn from_64bit_hex_float(input: &str) -> Result<f64, Error> {
+        let bits: u64 = HexFloat::parse_exact(input, Format::BINARY64)?;
+        Ok(f64::from_bits(bits))
+    }
+
+    pub fn from_decimal(input: &str, format: Format, rounding: RoundingMode) -> Result<u64, Error> {
+        DecimalParser::parse(input, format, rounding)
+    }
+
+    pub fn to_32bit_exact_decimal(values: Vec<u32>) -> Result<String, Error> {
+        let bits: u64 = Self::to_bits(&values, Format::BINARY32)?;
+        Ok(DecimalFormatter::exact(Format::BINARY32, bits))
+    }
+
+    pub fn to_64bit_exact_decimal(values: Vec<u32>) -> Result<String, Error> {
+        let bits: u64 = Self::to_bits(&values, Format::BINARY64)?;
+        Ok(DecimalFormatter::exact(Format::BINARY64, bits))
+    }
+
+    pub fn to_exact_decimal(values: Vec<u32>, format: Format) -> Result<String, Error> {
+        let bits: u64 = Self::to_bits(&values, format)?;
+        Ok(DecimalFormatter::exact(format, bits))
+    }
+
+    pub fn to_shortest_decimal(values: Vec<u32>, format: Format) -> Result<String, Error> {
+        let bits: u64 = Self::to_bits(&values, format)?;
+        Ok(DecimalFormatter::shortest(format, bits))
+    }
+
+    fn to_bits(values: &[u32], format: Format) -> Result<u64, Error> {
         if values.is_empty() {
-            return Err(ValidationError::EmptyValues);
+            return Err(Error::EmptyValues);
         }
-        for v in values.iter() {
-            for x in &mut format!("{:08b}", v).chars() {
-                match u8::from_str_radix(&x.to_string(), 2) {
-                    Ok(value) => binaries.push(value),
-                    Err(_) => return Err(ValidationError::ParseError),
-                }
+        if values.len() * 8 != format.total_bits() as usize {
+            return Err(Error::InvalidBitLength {
+                expected: format.total_bits() as usize,
+                actual: values.len() * 8,
+            });
+        }
+        let mut bits: u64 = 0;
+        for (index, v) in values.iter().enumerate() {
+            if *v > 0xFF {
+                return Err(Error::InvalidByte { index, value: *v });
             }
+            bits = (bits << 8) | *v as u64;
         }
-        Ok(binaries)
+        Ok(bits)
     }
 
-    fn get_sign_bit(binaries: &[u8]) -> Result<i8, ValidationError> {
-        if binaries.is_empty() {
-            return Err(ValidationError::EmptySignBit);
+    pub fn to_binary(values: Vec<u32>) -> Result<Vec<u8>, Error> {
+        if values.is_empty() {
+            return Err(Error::EmptyValues);
         }
-        match binaries.first() {
-            Some(value) => Ok(if value == &0 { 1 } else { -1 }),
-            None => Err(ValidationError::InvalidSignBit),
+        let mut binaries: Vec<u8> = Vec::with_capacity(values.len() * 8);
+        for (index, v) in values.iter().enumerate() {
+            if *v > 0xFF {
+                return Err(Error::InvalidByte { index, value: *v });
+            }
+            for shift in (0..8).rev() {
+                binaries.push(((v >> shift) & 1) as u8);
+            }
         }
+        Ok(binaries)
     }
 
-    pub fn to_64bit_float(values: Vec<u32>) -> Result<f64, ValidationError> {
-        let binaries: Vec<u8> = Self::to_binary(values)?;
-        let sign_bit: i8 = Self::get_sign_bit(&binaries)?;
-
-        // Exponent
-        match binaries.get(1..12) {
-            Some(exponent_binaries) => {
-                // Mantissa
-                match binaries.get(12..) {
-                    Some(mantissa_binaries) => {
-                        IEEE754_64bit::validate(exponent_binaries, mantissa_binaries)?;
-                        let exponent: i32 = IEEE754_64bit::get_exponent(exponent_binaries)?;
-                        let value: f64 = IEEE754_64bit::get_mantissa(mantissa_binaries, exponent)?;
-                        match sign_bit {
-                            1 => Ok(value),
-                            -1 => Ok(-(value)),
-                            _ => Err(ValidationError::InvalidSignBit),
-                        }
-                    }
-                    None => Err(ValidationError::InvalidMantissa),
-                }
-            }
-            None => Err(ValidationError::InvalidExponent),
-        }
+    pub fn decode_32bit_slice(bytes: &[u8], endianness: Endianness) -> Vec<Result<f32, Error>> {
+        Batch::decode_32bit(bytes, endianness)
     }
-    pub fn to_32bit_float(values: Vec<u32>) -> Result<f32, ValidationError> {
-        let binaries: Vec<u8> = Self::to_binary(values)?;
-        let sign_bit: i8 = Self::get_sign_bit(&binaries)?;
-
-        // Exponent
-        match binaries.get(1..9) {
-            Some(exponent_binaries) => {
-                // Mantissa
-                match binaries.get(9..) {
-                    Some(mantissa_binaries) => {
-                        IEEE754_32bit::validate(exponent_binaries, mantissa_binaries)?;
-                        let exponent: i32 = IEEE754_32bit::get_exponent(exponent_binaries)?;
-                        let value: f32 = IEEE754_32bit::get_mantissa(mantissa_binaries, exponent)?;
-                        match sign_bit {
-                            1 => Ok(value),
-                            -1 => Ok(-(value)),
-                            _ => Err(ValidationError::InvalidSignBit),
-                        }
-                    }
-
-                    None => Err(ValidationError::InvalidMantissa),
-                }
-            }
-            None => Err(ValidationError::InvalidExponent),
-        }
+
+    pub fn decode_64bit_slice(bytes: &[u8], endianness: Endianness) -> Vec<Result<f64, Error>> {
+        Batch::decode_64bit(bytes, endianness)
+    }
+
+    pub fn encode_32bit_slice(values: &[f32], endianness: Endianness) -> Vec<u8> {
+        Batch::encode_32bit(values, endianness)
+    }
+
+    pub fn encode_64bit_slice(values: &[f64], endianness: Endianness) -> Vec<u8> {
+        Batch::encode_64bit(values, endianness)
+    }
+
+    pub fn to_64bit_float(values: Vec<u32>) -> Result<f64, Error> {
+        let bits: u64 = Self::to_bits(&values, Format::BINARY64)?;
+        IEEE754_64bit::decode(bits)
+    }
+
+    pub fn to_32bit_float(values: Vec<u32>) -> Result<f32, Error> {
+        let bits: u64 = Self::to_bits(&values, Format::BINARY32)?;
+        IEEE754_32bit::decode(bits as u32)
     }
 }
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
     use crate::helper::ComputeMantissaBits;
+    use crate::ieee754::Grouping;
     use std::f64;
 
     #[test]
@@ -254,6 +366,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_64bit_to_hex() {
         let values = 10.001;
         let output = IEEE754::to_64bit_hex(values.clone());
@@ -293,6 +406,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy, clippy::excessive_precision)]
     fn test_32bit_to_hex() {
         let values = 10.001;
         let output = IEEE754::to_32bit_hex(values.clone());
@@ -331,6 +445,280 @@ mod tests {
         assert_eq!(output.unwrap(), "43A6AAAB");
     }
 
+    #[test]
+    fn test_hex_with_options() {
+        let values = -33.333332;
+        let options = FormatOptions::new().uppercase(false).prefix(true);
+        let output = IEEE754::to_32bit_hex_with(values, &options);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 0xc2055555");
+        assert_eq!(output.unwrap(), "0xc2055555");
+
+        let values = -85.125;
+        let options = FormatOptions::new().grouping(Grouping::Byte);
+        let output = IEEE754::to_64bit_hex_with(values, &options);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: C0 55 48 00 00 00 00 00");
+        assert_eq!(output.unwrap(), "C0 55 48 00 00 00 00 00");
+
+        let values = -85.125;
+        let options = FormatOptions::new()
+            .grouping(Grouping::Byte)
+            .endianness(Endianness::Little);
+        let output = IEEE754::to_32bit_binary_with(values, &options);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 00000000 01000000 10101010 11000010");
+        assert_eq!(output.unwrap(), "00000000 01000000 10101010 11000010");
+    }
+
+    #[test]
+    fn test_from_hex() {
+        let values = "C2055555";
+        let output = IEEE754::from_32bit_hex(values);
+        println!("Input: {}", values);
+        println!("Expected Output: {}", -33.333332);
+        assert_eq!(output.unwrap(), -33.333332);
+
+        let values = "0xC0 0x55 0x48 0x00 0x00 0x00 0x00 0x00";
+        let output = IEEE754::from_64bit_hex(values);
+        println!("Input: {}", values);
+        println!("Expected Output: {}", -85.125);
+        assert_eq!(output.unwrap(), -85.125);
+
+        let values = "3c00";
+        let output = IEEE754::from_hex(values, Format::BINARY16);
+        println!("Input: {}", values);
+        println!("Expected Output: 0x3C00");
+        assert_eq!(output.unwrap(), 0x3C00);
+
+        let values = "C2 05 5Z 55";
+        let output = IEEE754::from_32bit_hex(values);
+        println!("Input: {}", values);
+        println!("Expected Output: Error(UnexpectedCharacter)");
+        assert!(matches!(
+            output,
+            Err(Error::UnexpectedCharacter {
+                position: 7,
+                character: 'Z'
+            })
+        ));
+    }
+
+    #[test]
+    fn test_binary_string() {
+        let values = -33.333332;
+        let options = FormatOptions::new().grouping(Grouping::Fields);
+        let output = IEEE754::to_32bit_binary_with(values, &options);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 1 10000100 00001010101010101010101");
+        assert_eq!(output.unwrap(), "1 10000100 00001010101010101010101");
+
+        let values = "1 10000100 00001010101010101010101";
+        let output = IEEE754::from_32bit_binary(values);
+        println!("Input: {}", values);
+        println!("Expected Output: {}", -33.333332);
+        assert_eq!(output.unwrap(), -33.333332);
+
+        let values = "1 10000000101 0101010010000000000000000000000000000000000000000000";
+        let output = IEEE754::from_64bit_binary(values);
+        println!("Input: {}", values);
+        println!("Expected Output: {}", -85.125);
+        assert_eq!(output.unwrap(), -85.125);
+
+        let values = "0 01111 0000000000";
+        let output = IEEE754::from_binary(values, Format::BINARY16);
+        println!("Input: {}", values);
+        println!("Expected Output: 0x3C00");
+        assert_eq!(output.unwrap(), 0x3C00);
+    }
+
+    #[test]
+    fn test_hex_float() {
+        let values = -33.333332;
+        let output = IEEE754::to_32bit_hex_float(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: -0x1.0aaaaap+5");
+        assert_eq!(output, "-0x1.0aaaaap+5");
+
+        let values = -33.33333333;
+        let output = IEEE754::to_64bit_hex_float(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: -0x1.0aaaaaaa38226p+5");
+        assert_eq!(output, "-0x1.0aaaaaaa38226p+5");
+
+        let values = "-0x1.0aaaaap+5";
+        let output = IEEE754::from_32bit_hex_float(values);
+        println!("Input: {}", values);
+        println!("Expected Output: {}", -33.333332);
+        assert_eq!(output.unwrap(), -33.333332);
+
+        let values = "0x1.921fb54442d18p+1";
+        let output = IEEE754::from_64bit_hex_float(values);
+        println!("Input: {}", values);
+        println!("Expected Output: {}", f64::consts::PI);
+        assert_eq!(output.unwrap(), f64::consts::PI);
+
+        let values = "0x1.921fb54442d18p+1";
+        let output = IEEE754::from_32bit_hex_float(values);
+        println!("Input: {}", values);
+        println!("Expected Output: Error(InexactValue)");
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_exact_decimal() {
+        let values = vec![0x3f, 0xb9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9a];
+        let output = IEEE754::to_64bit_exact_decimal(values.clone());
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 0.1000000000000000055511151231257827021181583404541015625");
+        assert_eq!(
+            output.unwrap(),
+            "0.1000000000000000055511151231257827021181583404541015625"
+        );
+
+        let values = vec![0x00, 0x00, 0x00, 0x01];
+        let output = IEEE754::to_32bit_exact_decimal(values.clone()).unwrap();
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 0.00000000000000000000000000000000000000000000140129846...");
+        assert!(output.starts_with("0.00000000000000000000000000000000000000000000140129846"));
+        assert!(output.ends_with("8836212158203125"));
+
+        let values = vec![0x3c, 0x00];
+        let output = IEEE754::to_exact_decimal(values.clone(), Format::BINARY16);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 1");
+        assert_eq!(output.unwrap(), "1");
+
+        let values = vec![0x35, 0x55];
+        let output = IEEE754::to_shortest_decimal(values.clone(), Format::BINARY16);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 0.3333");
+        assert_eq!(output.unwrap(), "0.3333");
+
+        let values = vec![0xc0, 0x2d, 0xf8];
+        let output = IEEE754::to_32bit_exact_decimal(values.clone());
+        println!("Input: {:x?}", values);
+        println!("Expected Output: Error");
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn test_decode_encode() {
+        // 0.5, previously outside of the supported range
+        let values = [0x3f, 0x00, 0x00, 0x00];
+        let output = IEEE754::decode_32bit(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: {}", 0.5);
+        assert_eq!(0.5, output.unwrap());
+
+        // Smallest subnormal
+        let values = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        let output = IEEE754::decode_64bit(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: {}", 5e-324);
+        assert_eq!(5e-324, output.unwrap());
+
+        // NaN
+        let values = [0x7f, 0xc0, 0x00, 0x00];
+        let output = IEEE754::decode_32bit(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: Error(ExponentAll1s)");
+        assert_eq!(output, Err(Error::ExponentAll1s));
+
+        let values = 1.0e-3;
+        let output = IEEE754::encode_32bit(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: [3a, 83, 12, 6f]");
+        assert_eq!(output, [0x3a, 0x83, 0x12, 0x6f]);
+
+        let values = f64::MAX;
+        let output = IEEE754::encode_64bit(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: [7f, ef, ff, ff, ff, ff, ff, ff]");
+        assert_eq!(output, [0x7f, 0xef, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+
+        let values = 1.0e-3;
+        let output = IEEE754::to_32bit_hex(values);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 3A83126F");
+        assert_eq!(output.unwrap(), "3A83126F");
+
+        let values = vec![0, 1, 1, 1, 1, 0, 1, 0];
+        let output = IEEE754::to_hex(values.clone());
+        println!("Input: {:x?}", values);
+        println!("Expected Output: 7A");
+        assert_eq!(output.unwrap(), "7A");
+
+        // Evaluated at compile time
+        const HALF: [u8; 4] = IEEE754::encode_32bit(0.5);
+        const DECODED: Result<f64, Error> =
+            IEEE754::decode_64bit([0x40, 0x09, 0x21, 0xfb, 0x54, 0x44, 0x2d, 0x18]);
+        println!("Input: {:x?}", HALF);
+        println!("Expected Output: [3f, 00, 00, 00]");
+        assert_eq!(HALF, [0x3f, 0x00, 0x00, 0x00]);
+        assert_eq!(DECODED, Ok(f64::consts::PI));
+    }
+
+    #[test]
+    fn test_get_binary_exponent() {
+        // 0.5, whose bits used to come out shifted
+        let binary = IEEE754_32bit::get_binary(0.5).unwrap();
+        assert_eq!(IEEE754::to_hex(binary.clone()).unwrap(), "3F000000");
+        assert_eq!(IEEE754_32bit::get_exponent(&binary[1..9]), Ok(-1));
+        assert_eq!(IEEE754_32bit::get_mantissa(&binary[9..], -1), Ok(0.5));
+        let binary = IEEE754_64bit::get_binary(-0.5).unwrap();
+        assert_eq!(IEEE754::to_hex(binary.clone()).unwrap(), "BFE0000000000000");
+        assert_eq!(IEEE754_64bit::get_exponent(&binary[1..12]), Ok(-1));
+
+        // 1.0 and the smallest subnormal no longer share exponent 0
+        let binary = IEEE754_32bit::get_binary(1.0).unwrap();
+        assert_eq!(IEEE754_32bit::get_exponent(&binary[1..9]), Ok(0));
+        assert_eq!(IEEE754_32bit::get_mantissa(&binary[9..], 0), Ok(1.0));
+        let subnormal = f32::from_bits(1);
+        let binary = IEEE754_32bit::get_binary(subnormal).unwrap();
+        assert_eq!(IEEE754::to_hex(binary.clone()).unwrap(), "00000001");
+        assert_eq!(IEEE754_32bit::get_exponent(&binary[1..9]), Ok(-127));
+        assert_eq!(
+            IEEE754_32bit::get_mantissa(&binary[9..], -127),
+            Ok(subnormal)
+        );
+        let subnormal = f64::from_bits(0x000F_FFFF_FFFF_FFFF);
+        let binary = IEEE754_64bit::get_binary(subnormal).unwrap();
+        assert_eq!(IEEE754_64bit::get_exponent(&binary[1..12]), Ok(-1023));
+        assert_eq!(
+            IEEE754_64bit::get_mantissa(&binary[12..], -1023),
+            Ok(subnormal)
+        );
+        assert_eq!(
+            IEEE754_32bit::get_mantissa(&binary[12..35], -128),
+            Err(Error::InvalidExponent)
+        );
+    }
+
+    #[test]
+    fn test_slices() {
+        let values = [0x40, 0x20, 0x00, 0x00, 0xff, 0x80, 0x00, 0x00];
+        let output = IEEE754::decode_32bit_slice(&values, Endianness::Big);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: [Ok(2.5), Err(ExponentAll1s)]");
+        assert_eq!(output, vec![Ok(2.5), Err(Error::ExponentAll1s)]);
+
+        let values = [-74.74597276138431];
+        let output = IEEE754::encode_64bit_slice(&values, Endianness::Big);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: [c0, 52, af, be, 04, 89, 76, 8e]");
+        assert_eq!(output, vec![0xc0, 0x52, 0xaf, 0xbe, 0x04, 0x89, 0x76, 0x8e]);
+
+        let output = IEEE754::decode_64bit_slice(&output, Endianness::Big);
+        assert_eq!(output, vec![Ok(-74.74597276138431)]);
+
+        let values = [1.0f32];
+        let output = IEEE754::encode_32bit_slice(&values, Endianness::Little);
+        println!("Input: {:x?}", values);
+        println!("Expected Output: [00, 00, 80, 3f]");
+        assert_eq!(output, vec![0x00, 0x00, 0x80, 0x3f]);
+    }
+
     #[test]
     fn test_64bit() {
         // -74.74597276138431
@@ -1 +1,15 @@
-fn main() {}
+mod cli;
+
+use std::io;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let code = cli::run(
+        &args,
+        &mut io::stdin().lock(),
+        &mut io::stdout().lock(),
+        &mut io::stderr().lock(),
+    );
+    process::exit(code);
+}